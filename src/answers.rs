@@ -0,0 +1,126 @@
+// The registry of known answers, read from `answers.txt`, which every solver
+// (including its variants) is checked against by `aoc verify`.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/answers.txt");
+
+// the input name of the input bundled with each day
+pub const BUNDLED: &str = "bundled";
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct KnownAnswer {
+    pub day: u32,
+    pub part: u32,
+    // BUNDLED, or a path relative to the answers file
+    pub input: String,
+    pub answer: String
+}
+
+pub struct Answers {
+    pub answers: Vec<KnownAnswer>,
+    // the directory that input paths are relative to
+    pub base: PathBuf
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+
+        let answers = parse(&contents)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let base = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        Ok(Self { answers, base })
+    }
+
+    pub fn for_part(&self, day: u32, part: u32) -> impl Iterator<Item = &KnownAnswer> {
+        self.answers.iter().filter(move |a| a.day == day && a.part == part)
+    }
+
+    pub fn get(&self, day: u32, part: u32, input: &str) -> Option<&KnownAnswer> {
+        self.for_part(day, part).find(|a| a.input == input)
+    }
+
+    // returns None for the bundled input
+    pub fn input_path(&self, answer: &KnownAnswer) -> Option<PathBuf> {
+        if answer.input == BUNDLED {
+            None
+        } else {
+            Some(self.base.join(&answer.input))
+        }
+    }
+}
+
+// blank lines and lines starting with # are ignored
+pub fn parse(contents: &str) -> Result<Vec<KnownAnswer>, String> {
+    let mut answers: Vec<KnownAnswer> = vec![];
+
+    for (index, line) in contents.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields = line.split_ascii_whitespace().collect::<Vec<_>>();
+
+        let (day, part, input, answer) = match fields[..] {
+            [day, part, input, answer] => (day, part, input, answer),
+            _ => return Err(format!("line {}: expected <day> <part> <input> <answer>", index + 1))
+        };
+
+        let day = day.parse::<u32>()
+            .map_err(|_| format!("line {}: invalid day: {}", index + 1, day))?;
+        let part = part.parse::<u32>()
+            .map_err(|_| format!("line {}: invalid part: {}", index + 1, part))?;
+
+        if answers.iter().any(|a| a.day == day && a.part == part && a.input == input) {
+            return Err(format!("line {}: duplicate answer for day {} part {} ({})", index + 1, day, part, input));
+        }
+
+        answers.push(KnownAnswer { day, part, input: input.to_owned(), answer: answer.to_owned() });
+    }
+
+    Ok(answers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = parse("# comment\n\n1 1 bundled 514579\n1 2 inputs/example 241861950\n").unwrap();
+
+        assert_eq!(answers, vec![
+            KnownAnswer { day: 1, part: 1, input: "bundled".to_owned(), answer: "514579".to_owned() },
+            KnownAnswer { day: 1, part: 2, input: "inputs/example".to_owned(), answer: "241861950".to_owned() },
+        ]);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(parse("1 1 bundled").unwrap_err(), "line 1: expected <day> <part> <input> <answer>");
+        assert_eq!(parse("x 1 bundled 2").unwrap_err(), "line 1: invalid day: x");
+        assert_eq!(
+            parse("1 1 bundled 2\n1 1 bundled 3").unwrap_err(),
+            "line 2: duplicate answer for day 1 part 1 (bundled)"
+        );
+    }
+
+    #[test]
+    fn checked_in_answers_cover_every_part() {
+        let answers = Answers::load(Path::new(DEFAULT_PATH)).unwrap();
+
+        // a day just scaffolded by `aoc new` has no answers until it's solved
+        let solved = crate::registry::SOLVERS.iter()
+            .filter(|solver| answers.answers.iter().any(|a| a.day == solver.day));
+
+        for solver in solved {
+            assert!(answers.get(solver.day, solver.part, BUNDLED).is_some(), "{}", solver.label());
+        }
+    }
+}
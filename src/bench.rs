@@ -0,0 +1,123 @@
+// Repeatedly runs a solver, timing parsing and solving separately, and
+// summarizes the samples of each.
+use std::time::Duration;
+
+use crate::registry::{Run, SolveError, Solver};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration
+}
+
+impl Stats {
+    // returns None if there are no samples
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let count = sorted.len();
+        let nanos = sorted.iter().map(|d| d.as_nanos() as f64).collect::<Vec<_>>();
+
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count as f64;
+
+        let median = if count % 2 == 0 {
+            (sorted[count / 2 - 1] + sorted[count / 2]) / 2
+        } else {
+            sorted[count / 2]
+        };
+
+        Some(Self {
+            mean: Duration::from_nanos(mean.round() as u64),
+            median,
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+            min: sorted[0],
+            max: sorted[count - 1]
+        })
+    }
+}
+
+pub struct Bench {
+    pub iterations: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+    pub total: Stats
+}
+
+// runs the solver warmup times without recording, then iterations times
+pub fn bench(solver: &Solver, input: &str, warmup: usize, iterations: usize) -> Result<Option<Bench>, SolveError> {
+    for _ in 0..warmup {
+        (solver.run)(input)?;
+    }
+
+    let runs = (0..iterations)
+        .map(|_| (solver.run)(input))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(summarize(&runs))
+}
+
+// None if there are no runs
+fn summarize(runs: &[Run]) -> Option<Bench> {
+    let iterations = runs.len();
+
+    let samples = |time: fn(&Run) -> Duration| {
+        runs.iter().map(time).collect::<Vec<_>>()
+    };
+
+    Some(Bench {
+        iterations,
+        parse: Stats::from_samples(&samples(|run| run.parse_time))?,
+        solve: Stats::from_samples(&samples(|run| run.solve_time))?,
+        total: Stats::from_samples(&samples(|run| run.elapsed()))?
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(samples: &[u64]) -> Vec<Duration> {
+        samples.iter().map(|&x| Duration::from_micros(x)).collect()
+    }
+
+    #[test]
+    fn stats() {
+        let stats = Stats::from_samples(&micros(&[2, 4, 4, 4, 5, 5, 7, 9])).unwrap();
+
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.median, Duration::from_micros(4) + Duration::from_nanos(500));
+        assert_eq!(stats.stddev, Duration::from_micros(2));
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(stats.max, Duration::from_micros(9));
+    }
+
+    #[test]
+    fn stats_of_odd_samples() {
+        let stats = Stats::from_samples(&micros(&[3, 1, 2])).unwrap();
+
+        assert_eq!(stats.median, Duration::from_micros(2));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn bench_counts_iterations() {
+        let solver = crate::registry::find(1, 1, None).unwrap();
+        let bench = bench(solver, crate::day01::INPUT, 1, 3).unwrap().unwrap();
+
+        assert_eq!(bench.iterations, 3);
+        assert!(bench.total.min >= bench.solve.min);
+
+        // the iterations leave out the details, which only a detailed run has
+        assert!((solver.run)(crate::day01::INPUT).unwrap().details.is_empty());
+        assert!(!(solver.detailed)(crate::day01::INPUT).unwrap().details.is_empty());
+    }
+}
@@ -0,0 +1,332 @@
+// Arbitrary precision integers, with just the arithmetic the solvers need:
+// parsing, printing, comparing, adding, subtracting and multiplying.
+use std::cmp::Ordering;
+use std::fmt::{self, Display};
+use std::ops::{Add, Mul, Neg, Sub};
+use std::str::FromStr;
+
+// the magnitude is in base 2^32, least significant digit first, without
+// leading zeros, so that every integer has one representation; 0 has no
+// digits and isn't negative
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigInt {
+    negative: bool,
+    magnitude: Vec<u32>
+}
+
+// the largest power of 10 in a digit, for converting from and to decimal
+const DECIMAL_BASE: u32 = 1_000_000_000;
+const DECIMAL_DIGITS: usize = 9;
+
+impl BigInt {
+    fn new(negative: bool, mut magnitude: Vec<u32>) -> Self {
+        while magnitude.last() == Some(&0) {
+            magnitude.pop();
+        }
+
+        let negative = negative && !magnitude.is_empty();
+
+        Self { negative, magnitude }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.magnitude.is_empty()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+}
+
+fn compare_magnitudes(a: &[u32], b: &[u32]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut sum = Vec::with_capacity(a.len().max(b.len()) + 1);
+    let mut carry = 0u64;
+
+    for i in 0..a.len().max(b.len()) {
+        let digit = u64::from(*a.get(i).unwrap_or(&0)) + u64::from(*b.get(i).unwrap_or(&0)) + carry;
+        sum.push(digit as u32);
+        carry = digit >> 32;
+    }
+
+    sum.push(carry as u32);
+    sum
+}
+
+// a must not be smaller than b
+fn sub_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut difference = Vec::with_capacity(a.len());
+    let mut borrow = 0i64;
+
+    for (i, &digit) in a.iter().enumerate() {
+        let mut digit = i64::from(digit) - i64::from(*b.get(i).unwrap_or(&0)) - borrow;
+        borrow = 0;
+
+        if digit < 0 {
+            digit += 1 << 32;
+            borrow = 1;
+        }
+
+        difference.push(digit as u32);
+    }
+
+    difference
+}
+
+fn mul_magnitudes(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut product = vec![0u32; a.len() + b.len()];
+
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0u64;
+
+        for (j, &y) in b.iter().enumerate() {
+            let digit = u64::from(product[i + j]) + u64::from(x) * u64::from(y) + carry;
+            product[i + j] = digit as u32;
+            carry = digit >> 32;
+        }
+
+        product[i + b.len()] = carry as u32;
+    }
+
+    product
+}
+
+// multiplies magnitude by factor and adds addend, in place
+fn mul_add_small(magnitude: &mut Vec<u32>, factor: u32, addend: u32) {
+    let mut carry = u64::from(addend);
+
+    for digit in magnitude.iter_mut() {
+        let product = u64::from(*digit) * u64::from(factor) + carry;
+        *digit = product as u32;
+        carry = product >> 32;
+    }
+
+    if carry > 0 {
+        magnitude.push(carry as u32);
+    }
+}
+
+// divides magnitude by divisor in place, returning the remainder
+fn div_rem_small(magnitude: &mut [u32], divisor: u32) -> u32 {
+    let mut remainder = 0u64;
+
+    for digit in magnitude.iter_mut().rev() {
+        let dividend = (remainder << 32) | u64::from(*digit);
+        *digit = (dividend / u64::from(divisor)) as u32;
+        remainder = dividend % u64::from(divisor);
+    }
+
+    remainder as u32
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_magnitudes(&self.magnitude, &other.magnitude),
+            (true, true) => compare_magnitudes(&other.magnitude, &self.magnitude)
+        }
+    }
+}
+
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        BigInt::new(!self.negative, self.magnitude)
+    }
+}
+
+impl Add for BigInt {
+    type Output = BigInt;
+
+    fn add(self, other: BigInt) -> BigInt {
+        if self.negative == other.negative {
+            return BigInt::new(self.negative, add_magnitudes(&self.magnitude, &other.magnitude));
+        }
+
+        // the sign is that of the one with the larger magnitude
+        match compare_magnitudes(&self.magnitude, &other.magnitude) {
+            Ordering::Less => BigInt::new(other.negative, sub_magnitudes(&other.magnitude, &self.magnitude)),
+            _ => BigInt::new(self.negative, sub_magnitudes(&self.magnitude, &other.magnitude))
+        }
+    }
+}
+
+impl Sub for BigInt {
+    type Output = BigInt;
+
+    fn sub(self, other: BigInt) -> BigInt {
+        self + -other
+    }
+}
+
+impl Mul for BigInt {
+    type Output = BigInt;
+
+    fn mul(self, other: BigInt) -> BigInt {
+        BigInt::new(self.negative != other.negative, mul_magnitudes(&self.magnitude, &other.magnitude))
+    }
+}
+
+impl From<i64> for BigInt {
+    fn from(n: i64) -> Self {
+        let magnitude = n.unsigned_abs();
+
+        BigInt::new(n < 0, vec![magnitude as u32, (magnitude >> 32) as u32])
+    }
+}
+
+impl From<i32> for BigInt {
+    fn from(n: i32) -> Self {
+        BigInt::from(i64::from(n))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseBigIntError;
+
+impl Display for ParseBigIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid integer")
+    }
+}
+
+impl FromStr for BigInt {
+    type Err = ParseBigIntError;
+
+    // an optional sign followed by decimal digits, like the primitive integers
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s)
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigIntError);
+        }
+
+        let mut magnitude = vec![];
+
+        // the digits are all ASCII, so any byte offset is a char boundary
+        let first_chunk = match digits.len() % DECIMAL_DIGITS {
+            0 => DECIMAL_DIGITS,
+            len => len
+        };
+
+        let mut start = 0;
+        let mut end = first_chunk;
+
+        while start < digits.len() {
+            let chunk = &digits[start..end];
+            let factor = 10u32.pow(chunk.len() as u32);
+            mul_add_small(&mut magnitude, factor, chunk.parse::<u32>().map_err(|_| ParseBigIntError)?);
+
+            start = end;
+            end += DECIMAL_DIGITS;
+        }
+
+        Ok(BigInt::new(negative, magnitude))
+    }
+}
+
+impl Display for BigInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_zero() {
+            return write!(f, "0");
+        }
+
+        // the decimal digits in chunks of 9, least significant first
+        let mut magnitude = self.magnitude.clone();
+        let mut chunks = vec![];
+
+        while !magnitude.is_empty() {
+            chunks.push(div_rem_small(&mut magnitude, DECIMAL_BASE));
+
+            while magnitude.last() == Some(&0) {
+                magnitude.pop();
+            }
+        }
+
+        if self.negative {
+            write!(f, "-")?;
+        }
+
+        let mut chunks = chunks.iter().rev();
+
+        if let Some(most_significant) = chunks.next() {
+            write!(f, "{}", most_significant)?;
+        }
+
+        for chunk in chunks {
+            write!(f, "{:09}", chunk)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{check, equal, Integers};
+
+    fn big(s: &str) -> BigInt {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn decimal() {
+        for s in &["0", "7", "-7", "4294967296", "-18446744073709551616", "123456789012345678901234567890"] {
+            assert_eq!(big(s).to_string(), *s);
+        }
+
+        assert_eq!(big("+0012").to_string(), "12");
+        assert_eq!(big("-0"), BigInt::default());
+        assert_eq!(big("-0").to_string(), "0");
+
+        for s in &["", "-", "1_000", "12a", " 1", "--1"] {
+            assert_eq!(s.parse::<BigInt>(), Err(ParseBigIntError), "{:?}", s);
+        }
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = big("123456789012345678901234567890");
+        let b = big("-987654321098765432109876543210");
+
+        assert_eq!((a.clone() + b.clone()).to_string(), "-864197532086419753208641975320");
+        assert_eq!((a.clone() - b.clone()).to_string(), "1111111110111111111011111111100");
+        assert_eq!((b.clone() - b.clone()), BigInt::default());
+        assert_eq!((a * b).to_string(), "-121932631137021795226185032733622923332237463801111263526900");
+
+        assert_eq!(BigInt::from(i64::MIN).to_string(), i64::MIN.to_string());
+        assert_eq!((BigInt::from(i64::MAX) * BigInt::from(i64::MAX)).to_string(), (i128::from(i64::MAX) * i128::from(i64::MAX)).to_string());
+    }
+
+    #[test]
+    fn agrees_with_i128() {
+        let pairs = (Integers(i64::MIN..i64::MAX), Integers(i64::MIN..i64::MAX));
+
+        check(&pairs, |&(a, b)| {
+            let (big_a, big_b) = (BigInt::from(a), BigInt::from(b));
+            let (a, b) = (i128::from(a), i128::from(b));
+
+            equal((big_a.clone() + big_b.clone()).to_string(), (a + b).to_string())?;
+            equal((big_a.clone() - big_b.clone()).to_string(), (a - b).to_string())?;
+            equal(big_a.cmp(&big_b), a.cmp(&b))?;
+            equal(big(&(a * b).to_string()), big_a * big_b)
+        });
+    }
+}
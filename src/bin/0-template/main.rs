@@ -1,6 +0,0 @@
-const INPUT: &str = include_str!("input");
-
-fn main() {
-    let _ = INPUT;
-    println!("Hello, world!");
-}
@@ -1,59 +1,9 @@
-/* --- Day 1: Report Repair ---
-
-After saving Christmas five years in a row, you've decided to take a vacation at a nice resort on a tropical island. Surely, Christmas will go on without you.
-
-The tropical island has its own currency and is entirely cash-only. The gold coins used there have a little picture of a starfish; the locals just call them stars. None of the currency exchanges seem to have heard of them, but somehow, you'll need to find fifty of these coins by the time you arrive so you can pay the deposit on your room.
-
-To save your vacation, you need to get all fifty stars by December 25th.
-
-Collect stars by solving puzzles. Two puzzles will be made available on each day in the Advent calendar; the second puzzle is unlocked when you complete the first. Each puzzle grants one star. Good luck!
-
-Before you leave, the Elves in accounting just need you to fix your expense report (your puzzle input); apparently, something isn't quite adding up.
-
-Specifically, they need you to find the two entries that sum to 2020 and then multiply those two numbers together.
-
-For example, suppose your expense report contained the following:
-
-1721
-979
-366
-299
-675
-1456
-
-In this list, the two entries that sum to 2020 are 1721 and 299. Multiplying them together produces 1721 * 299 = 514579, so the correct answer is 514579.
-
-Of course, your expense report is much larger. Find the two entries that sum to 2020; what do you get if you multiply them together?
-*/
-const INPUT: &str = include_str!("input");
-const TARGET: i32 = 2020;
+use advent_of_code_2020::{day01, input};
 
 fn main() {
-    let mut numbers = INPUT
-        .split_ascii_whitespace()
-        .map(|s| s.parse::<i32>().unwrap())
-        .collect::<Vec<_>>();
-
-    numbers.sort();
-
-    let mut front = 0;
-    let mut back = numbers.len() - 1;
-
-    loop {
-        if front >= back {
-            panic!("could not find result");
-        }
+    let input = input::from_args(day01::INPUT);
 
-        let current_result = numbers[front] + numbers[back];
+    let result = input::or_exit(day01::part1(&input)).expect("could not find result");
 
-        if current_result == TARGET {
-            println!("found 2020 = {} + {}", numbers[front], numbers[back]);
-            println!("{} * {} = {}",numbers[front], numbers[back], numbers[front] * numbers[back]);
-            break;
-        } else if current_result < TARGET {
-            front += 1;
-        } else {
-            back -= 1;
-        }
-    }
+    println!("result: {}", result);
 }
@@ -1,116 +1,9 @@
-/* --- Part Two ---
-
-To completely determine whether you have enough adapters, you'll need to figure out how many different ways they can be arranged. Every arrangement needs to connect the charging outlet to your device. The previous rules about when adapters can successfully connect still apply.
-
-The first example above (the one that starts with 16, 10, 15) supports the following arrangements:
-
-(0), 1, 4, 5, 6, 7, 10, 11, 12, 15, 16, 19, (22)
-(0), 1, 4, 5, 6, 7, 10, 12, 15, 16, 19, (22)
-(0), 1, 4, 5, 7, 10, 11, 12, 15, 16, 19, (22)
-(0), 1, 4, 5, 7, 10, 12, 15, 16, 19, (22)
-(0), 1, 4, 6, 7, 10, 11, 12, 15, 16, 19, (22)
-(0), 1, 4, 6, 7, 10, 12, 15, 16, 19, (22)
-(0), 1, 4, 7, 10, 11, 12, 15, 16, 19, (22)
-(0), 1, 4, 7, 10, 12, 15, 16, 19, (22)
-
-(The charging outlet and your device's built-in adapter are shown in parentheses.) Given the adapters from the first example, the total number of arrangements that connect the charging outlet to your device is 8.
-
-The second example above (the one that starts with 28, 33, 18) has many arrangements. Here are a few:
-
-(0), 1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31,
-32, 33, 34, 35, 38, 39, 42, 45, 46, 47, 48, 49, (52)
-
-(0), 1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31,
-32, 33, 34, 35, 38, 39, 42, 45, 46, 47, 49, (52)
-
-(0), 1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31,
-32, 33, 34, 35, 38, 39, 42, 45, 46, 48, 49, (52)
-
-(0), 1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31,
-32, 33, 34, 35, 38, 39, 42, 45, 46, 49, (52)
-
-(0), 1, 2, 3, 4, 7, 8, 9, 10, 11, 14, 17, 18, 19, 20, 23, 24, 25, 28, 31,
-32, 33, 34, 35, 38, 39, 42, 45, 47, 48, 49, (52)
-
-(0), 3, 4, 7, 10, 11, 14, 17, 20, 23, 25, 28, 31, 34, 35, 38, 39, 42, 45,
-46, 48, 49, (52)
-
-(0), 3, 4, 7, 10, 11, 14, 17, 20, 23, 25, 28, 31, 34, 35, 38, 39, 42, 45,
-46, 49, (52)
-
-(0), 3, 4, 7, 10, 11, 14, 17, 20, 23, 25, 28, 31, 34, 35, 38, 39, 42, 45,
-47, 48, 49, (52)
-
-(0), 3, 4, 7, 10, 11, 14, 17, 20, 23, 25, 28, 31, 34, 35, 38, 39, 42, 45,
-47, 49, (52)
-
-(0), 3, 4, 7, 10, 11, 14, 17, 20, 23, 25, 28, 31, 34, 35, 38, 39, 42, 45,
-48, 49, (52)
-
-In total, this set of adapters can connect the charging outlet to your device in 19208 distinct arrangements.
-
-You glance back down at your bag and try to remember why you brought so many adapters; there must be more than a trillion valid ways to arrange them! Surely, there must be an efficient way to count the arrangements.
-
-What is the total number of distinct ways you can arrange the adapters to connect the charging outlet to your device?
-*/
-use std::collections::HashMap;
-
-const INPUT: &str = include_str!("input");
+use advent_of_code_2020::{day10, input};
 
 fn main() {
-    let mut joltages = INPUT.lines()
-        .map(|line| line.parse::<i32>().unwrap())
-        .collect::<Vec<_>>();
-
-    joltages.sort();
-
-    let arrangements = count_arrangements(&joltages);
-    println!("arrangements: {}", arrangements);
-}
-
-fn count_arrangements(sorted_input: &Vec<i32>) -> usize {
-    let mut dynamic_results = HashMap::new();
-
-    // we need to account for the wall, which is 0
-    // we don't need to account for our device, because it doesn't affect the result
-    let mut input = sorted_input.clone();
-    input.insert(0, 0);
-
-    count_arrangements_recursive_dynamic(&input, 0, &mut dynamic_results)
-}
-
-fn count_arrangements_recursive_dynamic(
-    sorted_input: &Vec<i32>, from_index: usize, dynamic_results: &mut HashMap<usize, usize>
-) -> usize {
-    if let Some(&cached) = dynamic_results.get(&from_index) {
-        return cached;
-    }
-
-    if from_index == sorted_input.len() - 1 {
-        return 1;
-    }
-
-    let current_joltage = sorted_input[from_index];
-
-    let mut index = from_index + 1;
-    let mut result = 0;
-
-    loop {
-        if index >= sorted_input.len() {
-            break;
-        }
-
-        let joltage = sorted_input[index];
-
-        if joltage - current_joltage > 3 {
-            break;
-        }
-
-        result += count_arrangements_recursive_dynamic(sorted_input, index, dynamic_results);
+    let input = input::from_args(day10::INPUT);
 
-        index += 1;
-    }
+    let result = input::or_exit(day10::part2(&input));
 
-    dynamic_results.insert(from_index, result);
-    result
+    println!("result: {}", result);
 }
@@ -1,58 +1,9 @@
-/* --- Part Two ---
-
-While it appears you validated the passwords correctly, they don't seem to be what the Official Toboggan Corporate Authentication System is expecting.
-
-The shopkeeper suddenly realizes that he just accidentally explained the password policy rules from his old job at the sled rental place down the street! The Official Toboggan Corporate Policy actually works a little differently.
-
-Each policy actually describes two positions in the password, where 1 means the first character, 2 means the second character, and so on. (Be careful; Toboggan Corporate Policies have no concept of "index zero"!) Exactly one of these positions must contain the given letter. Other occurrences of the letter are irrelevant for the purposes of policy enforcement.
-
-Given the same example list from above:
-
-    1-3 a: abcde is valid: position 1 contains a and position 3 does not.
-    1-3 b: cdefg is invalid: neither position 1 nor position 3 contains b.
-    2-9 c: ccccccccc is invalid: both position 2 and position 9 contain c.
-
-How many passwords are valid according to the new interpretation of the policies?
-*/
-use regex::Regex;
-
-const INPUT: &str = include_str!("input");
+use advent_of_code_2020::{day02, input};
 
 fn main() {
-    let line_parser = Regex::new(r"(\d+)-(\d+) (.): (.*)$").unwrap();
-
-    let valid_password_count = INPUT
-        .lines()
-        .map(|line| {
-            // parse line into (min, max, char, password)
-            let captures = line_parser.captures(line)
-                .expect("failed to parse line");
-
-            print!("{}: ", line);
-
-            (
-                // note: 1-indexed
-                captures[1].parse::<usize>().unwrap() - 1,
-                captures[2].parse::<usize>().unwrap() - 1,
-                captures[3].chars().nth(0).unwrap(),
-                captures[4].to_owned()
-            )
-        }).map(|(first_pos, second_pos, ch, password)| {
-            // verify password is correct
-
-            let is_match = |pos: usize| {
-                password.chars().nth(pos)
-                    .map(|c| c == ch)
-                    .unwrap_or(false)
-            };
-
-            let result = is_match(first_pos) ^ is_match(second_pos);
-
-            println!("{}", result);
+    let input = input::from_args(day02::INPUT);
 
-            result
-        }).filter(|result| result.to_owned())
-        .count();
+    let result = input::or_exit(day02::part2(&input));
 
-    dbg!(valid_password_count);
+    println!("result: {}", result);
 }
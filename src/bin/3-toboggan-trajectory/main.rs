@@ -1,99 +1,9 @@
-/* --- Day 3: Toboggan Trajectory ---
-
-With the toboggan login problems resolved, you set off toward the airport. While travel by toboggan might be easy, it's certainly not safe: there's very minimal steering and the area is covered in trees. You'll need to see which angles will take you near the fewest trees.
-
-Due to the local geology, trees in this area only grow on exact integer coordinates in a grid. You make a map (your puzzle input) of the open squares (.) and trees (#) you can see. For example:
-
-..##.......
-#...#...#..
-.#....#..#.
-..#.#...#.#
-.#...##..#.
-..#.##.....
-.#.#.#....#
-.#........#
-#.##...#...
-#...##....#
-.#..#...#.#
-
-These aren't the only trees, though; due to something you read about once involving arboreal genetics and biome stability, the same pattern repeats to the right many times:
-
-..##.........##.........##.........##.........##.........##.......  --->
-#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..
-.#....#..#..#....#..#..#....#..#..#....#..#..#....#..#..#....#..#.
-..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#
-.#...##..#..#...##..#..#...##..#..#...##..#..#...##..#..#...##..#.
-..#.##.......#.##.......#.##.......#.##.......#.##.......#.##.....  --->
-.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#
-.#........#.#........#.#........#.#........#.#........#.#........#
-#.##...#...#.##...#...#.##...#...#.##...#...#.##...#...#.##...#...
-#...##....##...##....##...##....##...##....##...##....##...##....#
-.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#  --->
-
-You start on the open square (.) in the top-left corner and need to reach the bottom (below the bottom-most row on your map).
-
-The toboggan can only follow a few specific slopes (you opted for a cheaper model that prefers rational numbers); start by counting all the trees you would encounter for the slope right 3, down 1:
-
-From your starting position at the top-left, check the position that is right 3 and down 1. Then, check the position that is right 3 and down 1 from there, and so on until you go past the bottom of the map.
-
-The locations you'd check in the above example are marked here with O where there was an open square and X where there was a tree:
-
-..##.........##.........##.........##.........##.........##.......  --->
-#..O#...#..#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..
-.#....X..#..#....#..#..#....#..#..#....#..#..#....#..#..#....#..#.
-..#.#...#O#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#
-.#...##..#..X...##..#..#...##..#..#...##..#..#...##..#..#...##..#.
-..#.##.......#.X#.......#.##.......#.##.......#.##.......#.##.....  --->
-.#.#.#....#.#.#.#.O..#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#
-.#........#.#........X.#........#.#........#.#........#.#........#
-#.##...#...#.##...#...#.X#...#...#.##...#...#.##...#...#.##...#...
-#...##....##...##....##...#X....##...##....##...##....##...##....#
-.#..#...#.#.#..#...#.#.#..#...X.#.#..#...#.#.#..#...#.#.#..#...#.#  --->
-
-In this example, traversing the map using this slope would cause you to encounter 7 trees.
-
-Starting at the top-left corner of your map and following a slope of right 3 and down 1, how many trees would you encounter?
-*/
-const INPUT: &str = include_str!("input");
+use advent_of_code_2020::{day03, input};
 
 fn main() {
-    let map = Map::from(INPUT.lines());
-
-    let rows = 1..(map.height());
-
-    let trees_hit = rows.filter(|row| {
-        let col = 3 * row;
-        map.is_tree(row.to_owned(), col)
-    }).count();
-
-    println!("trees hit: {}", trees_hit);
-}
-
-struct Map {
-    // true for tree, false for empty space
-    lines: Vec<Vec<bool>>,
-    width: usize
-}
-
-impl Map {
-    pub fn from<I, S>(input: I) -> Self
-    where I: Iterator<Item = S>, S : AsRef<str> {
-        let lines = input.map(|line| {
-            let chars = line.as_ref().chars();
-            let trees = chars.map(|c| c == '#');
-            trees.collect::<Vec<_>>()
-        }).collect::<Vec<_>>();
-
-        let width = lines[0].len();
-
-        Self { lines, width }
-    }
+    let input = input::from_args(day03::INPUT);
 
-    pub fn height(&self) -> usize {
-        self.lines.len()
-    }
+    let result = input::or_exit(day03::part1(&input));
 
-    pub fn is_tree(&self, row: usize, col: usize) -> bool {
-        self.lines[row][col % self.width]
-    }
+    println!("result: {}", result);
 }
@@ -1,88 +1,9 @@
-/* --- Day 4: Passport Processing ---
-
-You arrive at the airport only to realize that you grabbed your North Pole Credentials instead of your passport. While these documents are extremely similar, North Pole Credentials aren't issued by a country and therefore aren't actually valid documentation for travel in most of the world.
-
-It seems like you're not the only one having problems, though; a very long line has formed for the automatic passport scanners, and the delay could upset your travel itinerary.
-
-Due to some questionable network security, you realize you might be able to solve both of these problems at the same time.
-
-The automatic passport scanners are slow because they're having trouble detecting which passports have all required fields. The expected fields are as follows:
-
-    byr (Birth Year)
-    iyr (Issue Year)
-    eyr (Expiration Year)
-    hgt (Height)
-    hcl (Hair Color)
-    ecl (Eye Color)
-    pid (Passport ID)
-    cid (Country ID)
-
-Passport data is validated in batch files (your puzzle input). Each passport is represented as a sequence of key:value pairs separated by spaces or newlines. Passports are separated by blank lines.
-
-Here is an example batch file containing four passports:
-
-ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
-byr:1937 iyr:2017 cid:147 hgt:183cm
-
-iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
-hcl:#cfa07d byr:1929
-
-hcl:#ae17e1 iyr:2013
-eyr:2024
-ecl:brn pid:760753108 byr:1931
-hgt:179cm
-
-hcl:#cfa07d eyr:2025 pid:166559648
-iyr:2011 ecl:brn hgt:59in
-
-The first passport is valid - all eight fields are present. The second passport is invalid - it is missing hgt (the Height field).
-
-The third passport is interesting; the only missing field is cid, so it looks like data from North Pole Credentials, not a passport at all! Surely, nobody would mind if you made the system temporarily ignore missing cid fields. Treat this "passport" as valid.
-
-The fourth passport is missing two fields, cid and byr. Missing cid is fine, but missing any other field is not, so this passport is invalid.
-
-According to the above rules, your improved system would report 2 valid passports.
-
-Count the number of valid passports - those that have all required fields. Treat cid as optional. In your batch file, how many passports are valid?
-*/
-use std::collections::HashMap;
-
-use lazy_static::lazy_static;
-use regex::Regex;
-
-const INPUT: &str = include_str!("input");
-
-const REQUIRED_FIELDS: &[&str] = &[
-    "byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"
-];
+use advent_of_code_2020::{day04, input};
 
 fn main() {
-    // passports separated by an empty line
-    let passport_entries = INPUT.split("\n\n");
-
-    let valid_passports = passport_entries
-        .map(parse_passport)
-        .filter(|v| *v)
-        .count();
-
-    println!("valid passports: {}", valid_passports);
-}
-
-// returns whether passport is valid
-fn parse_passport(description: &str) -> bool {
-    let mut fields_found = REQUIRED_FIELDS.iter()
-        .map(|field_name| (field_name.to_string(), false))
-        .collect::<HashMap<_, _>>();
-
-    lazy_static! {
-        static ref FIELD_PARSER: Regex = Regex::new("(.{3}):.*").unwrap();
-    }
+    let input = input::from_args(day04::INPUT);
 
-    for field in description.split_ascii_whitespace() {
-        let captures = FIELD_PARSER.captures(field).unwrap();
-        let field_name = captures[1].to_owned();
-        fields_found.insert(field_name, true);
-    }
+    let result = input::or_exit(day04::part1(&input));
 
-    fields_found.values().into_iter().all(|v| *v)
+    println!("result: {}", result);
 }
@@ -0,0 +1,68 @@
+use std::collections::HashMap;
+
+// command line arguments, split into positional arguments and `--name value`
+// options
+pub struct Args {
+    pub positional: Vec<String>,
+    options: HashMap<String, String>
+}
+
+impl Args {
+    // value_options lists the options which take a value, and flags the ones
+    // which don't
+    pub fn parse<I>(args: I, value_options: &[&str], flags: &[&str]) -> Result<Self, String>
+    where I: Iterator<Item = String> {
+        let mut positional = vec![];
+        let mut options = HashMap::new();
+        let mut args = args.peekable();
+
+        while let Some(arg) = args.next() {
+            let name = match arg.strip_prefix("--") {
+                Some(name) => name,
+                None => {
+                    positional.push(arg);
+                    continue;
+                }
+            };
+
+            let (name, value) = match name.find('=') {
+                Some(index) => (&name[..index], Some(name[(index + 1)..].to_owned())),
+                None => (name, None)
+            };
+
+            let value = if value_options.contains(&name) {
+                match value.or_else(|| args.next()) {
+                    Some(value) => value,
+                    None => return Err(format!("option --{} requires a value", name))
+                }
+            } else if !flags.contains(&name) {
+                return Err(format!("unknown option: --{}", name));
+            } else if value.is_some() {
+                return Err(format!("option --{} does not take a value", name));
+            } else {
+                String::new()
+            };
+
+            options.insert(name.to_owned(), value);
+        }
+
+        Ok(Self { positional, options })
+    }
+
+    pub fn option(&self, name: &str) -> Option<&str> {
+        self.options.get(name).map(|s| s as &str)
+    }
+
+    // the names of the options and flags given
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.options.keys().map(|s| s as &str)
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.contains_key(name)
+    }
+
+    pub fn positional(&self, index: usize) -> Option<&str> {
+        self.positional.get(index).map(|s| s as &str)
+    }
+}
@@ -0,0 +1,186 @@
+use std::io::{self, Write};
+
+use advent_of_code_2020::day02::{self, policy::{self, PasswordPolicy, Units, Violation}};
+use advent_of_code_2020::input;
+use advent_of_code_2020::json::Value;
+
+use crate::args::Args;
+
+#[derive(Clone, Copy)]
+enum Format {
+    Text,
+    Csv,
+    Json
+}
+
+// audits a day 2 database: without a policy, counts the valid passwords under
+// every policy; with one, lists the entries which are invalid under it and why;
+// with two, lists the entries which are valid under one but not the other.
+// With --graphemes, the policies count grapheme clusters rather than chars.
+pub fn audit(args: &Args) -> Result<(), String> {
+    let names = args.positional.iter().skip(1).filter(|&arg| arg != "-").collect::<Vec<_>>();
+
+    let policies = names.iter()
+        .map(|name| policy::policy(name).ok_or_else(|| unknown_policy(name)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let format = match args.option("format") {
+        None | Some("text") => Format::Text,
+        Some("csv") => Format::Csv,
+        Some("json") => Format::Json,
+        Some(format) => return Err(format!("unknown format: {}", format))
+    };
+
+    let units = if args.flag("graphemes") { Units::Graphemes } else { Units::Chars };
+
+    let path = crate::input_path(args);
+    let database = input::read(path, day02::INPUT)
+        .map_err(|e| format!("failed to read {}: {}", path.unwrap_or("-"), e))?;
+
+    let entries = day02::parse_numbered(&database)
+        .map_err(|e| format!("invalid password database\n{}", e.render()))?;
+
+    if policies.len() > 2 {
+        return Err("at most two policies can be compared".to_owned());
+    }
+
+    let stdout = io::stdout();
+
+    match report(&mut stdout.lock(), &entries, &policies, units, format) {
+        // e.g. piped into head, which has all it wants
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        Err(e) => Err(format!("failed to write the audit: {}", e)),
+        Ok(()) => Ok(())
+    }
+}
+
+fn report(
+    out: &mut dyn Write, entries: &[(usize, day02::Entry)], policies: &[&dyn PasswordPolicy], units: Units, format: Format
+) -> io::Result<()> {
+    match *policies {
+        [policy] => {
+            let violations = policy::violations(entries, policy, units);
+            print_violations(out, &violations, None, format)?;
+
+            if let Format::Text = format {
+                writeln!(out, "{} of {} passwords are invalid under {}", violations.len(), entries.len(), policy.name())?;
+            }
+        }
+        [a, b] => {
+            let disagreements = policy::disagreements(entries, a, b, units);
+            print_violations(out, &disagreements, Some((a, b)), format)?;
+
+            if let Format::Text = format {
+                writeln!(out, "{} of {} passwords are valid under only one of {} and {}", disagreements.len(), entries.len(), a.name(), b.name())?;
+            }
+        }
+        _ => summarise(out, entries, units, format)?
+    }
+
+    Ok(())
+}
+
+fn summarise(out: &mut dyn Write, entries: &[(usize, day02::Entry)], units: Units, format: Format) -> io::Result<()> {
+    if let Format::Csv = format {
+        writeln!(out, "policy,valid,total,description")?;
+    }
+
+    for policy in policy::POLICIES {
+        let valid = entries.iter().filter(|(_, entry)| policy.is_valid(entry, units)).count();
+
+        match format {
+            Format::Text => writeln!(
+                out,
+                "{:<12} {} of {} passwords are valid: {}",
+                policy.name(), valid, entries.len(), policy.description()
+            ),
+            Format::Csv => writeln!(
+                out,
+                "{},{},{},{}",
+                policy.name(), valid, entries.len(), csv_field(policy.description())
+            ),
+            Format::Json => writeln!(out, "{}", Value::object(vec![
+                ("policy", Value::from(policy.name())),
+                ("valid", Value::number(valid)),
+                ("total", Value::number(entries.len())),
+                ("description", Value::from(policy.description())),
+            ]))
+        }?;
+    }
+
+    Ok(())
+}
+
+// each violation, and when comparing two policies, the one the entry is
+// valid under
+fn print_violations(
+    out: &mut dyn Write, violations: &[Violation], compared: Option<(&dyn PasswordPolicy, &dyn PasswordPolicy)>, format: Format
+) -> io::Result<()> {
+    let valid_under = |violation: &Violation| compared.map(|(a, b)| {
+        if violation.policy == a.name() { b.name() } else { a.name() }
+    });
+
+    if let Format::Csv = format {
+        match compared {
+            Some(_) => writeln!(out, "line,entry,valid_under,invalid_under,reason"),
+            None => writeln!(out, "line,entry,policy,reason")
+        }?;
+    }
+
+    for violation in violations {
+        let entry = violation.entry.to_string();
+
+        match (format, valid_under(violation)) {
+            (Format::Text, Some(valid)) => writeln!(
+                out,
+                "line {}: {}: valid under {}, not {}: {}",
+                violation.line, entry, valid, violation.policy, violation.reason
+            ),
+            (Format::Text, None) => writeln!(out, "line {}: {}: {}", violation.line, entry, violation.reason),
+            (Format::Csv, Some(valid)) => writeln!(
+                out,
+                "{},{},{},{},{}",
+                violation.line, csv_field(&entry), valid, violation.policy, csv_field(&violation.reason)
+            ),
+            (Format::Csv, None) => writeln!(
+                out,
+                "{},{},{},{}",
+                violation.line, csv_field(&entry), violation.policy, csv_field(&violation.reason)
+            ),
+            (Format::Json, valid) => {
+                let mut fields = vec![
+                    ("line", Value::number(violation.line)),
+                    ("entry", Value::from(entry)),
+                ];
+
+                match valid {
+                    Some(valid) => {
+                        fields.push(("valid_under", Value::from(valid)));
+                        fields.push(("invalid_under", Value::from(violation.policy)));
+                    }
+                    None => fields.push(("policy", Value::from(violation.policy)))
+                }
+
+                fields.push(("reason", Value::from(violation.reason.as_str())));
+                writeln!(out, "{}", Value::object(fields))
+            }
+        }?;
+    }
+
+    Ok(())
+}
+
+// quotes a field if it has a comma, a quote or a line break, doubling quotes
+fn csv_field(field: &str) -> String {
+    if field.contains(&[',', '"', '\n', '\r'][..]) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn unknown_policy(name: &str) -> String {
+    let names = policy::POLICIES.iter().map(|policy| policy.name()).collect::<Vec<_>>();
+
+    format!("unknown policy: {}, expected one of {}", name, names.join(", "))
+}
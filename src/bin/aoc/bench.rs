@@ -0,0 +1,76 @@
+use std::time::Duration;
+
+use advent_of_code_2020::bench::{self, Stats};
+
+use crate::args::Args;
+
+const DEFAULT_WARMUP: usize = 10;
+const DEFAULT_ITERATIONS: usize = 100;
+
+// benchmarks every variant of the selected parts, unless a variant is given
+pub fn bench(args: &Args) -> Result<(), String> {
+    let solvers = crate::select_solvers(args, true)?;
+    let input = crate::read_input(args, &solvers)?;
+
+    let warmup = count_option(args, "warmup", DEFAULT_WARMUP)?;
+    let iterations = count_option(args, "iterations", DEFAULT_ITERATIONS)?;
+
+    let csv = match args.option("format") {
+        None | Some("text") => false,
+        Some("csv") => true,
+        Some(format) => return Err(format!("unknown format: {}", format))
+    };
+
+    if iterations == 0 {
+        return Err("--iterations must be at least 1".to_owned());
+    }
+
+    if csv {
+        println!("day,part,variant,phase,iterations,mean_ns,median_ns,stddev_ns,min_ns,max_ns");
+    } else {
+        println!(
+            "{:<16}  {:<5}  {:>12}  {:>12}  {:>12}  {:>12}  {:>12}",
+            "solver", "phase", "mean", "median", "stddev", "min", "max"
+        );
+    }
+
+    for solver in solvers {
+        let input = input.as_deref().unwrap_or(solver.bundled_input());
+        let result = bench::bench(solver, input, warmup, iterations)
+            .map_err(|e| format!("invalid input for {}\n{}", solver.label(), e.render()))?
+            .ok_or_else(|| format!("no samples for {}", solver.label()))?;
+
+        let phases = [("parse", result.parse), ("solve", result.solve), ("total", result.total)];
+
+        for (phase, stats) in phases.iter() {
+            if csv {
+                println!(
+                    "{},{},{},{},{},{},{},{},{},{}",
+                    solver.day, solver.part, solver.variant.unwrap_or(""), phase, result.iterations,
+                    stats.mean.as_nanos(), stats.median.as_nanos(), stats.stddev.as_nanos(),
+                    stats.min.as_nanos(), stats.max.as_nanos()
+                );
+            } else {
+                println!("{:<16}  {:<5}  {}", solver.label(), phase, format_stats(stats));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn format_stats(stats: &Stats) -> String {
+    let format = |d: Duration| format!("{:>12}", format!("{:.2?}", d));
+
+    [stats.mean, stats.median, stats.stddev, stats.min, stats.max].iter()
+        .map(|&d| format(d))
+        .collect::<Vec<_>>()
+        .join("  ")
+}
+
+fn count_option(args: &Args, name: &str, default: usize) -> Result<usize, String> {
+    match args.option(name) {
+        Some(value) => value.parse::<usize>().map_err(|_| format!("invalid --{}: {}", name, value)),
+        None => Ok(default)
+    }
+}
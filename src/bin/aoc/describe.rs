@@ -0,0 +1,71 @@
+use advent_of_code_2020::json::Value;
+use advent_of_code_2020::statement::{self, Statement};
+
+use crate::args::Args;
+
+// prints the puzzle statement of a part, or of both parts, with its examples
+pub fn describe(args: &Args) -> Result<(), String> {
+    let day = match args.positional(1) {
+        Some(day) => crate::parse_number(day, "day")?,
+        None => return Err("no day given".to_owned())
+    };
+
+    let parts = match args.positional(2) {
+        Some(part) => vec![crate::parse_part(part)?],
+        None => vec![1, 2]
+    };
+
+    let json = match args.option("format") {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(format) => return Err(format!("unknown format: {}", format))
+    };
+
+    for part in parts {
+        let statement = statement::statement(day, part)
+            .ok_or_else(|| format!("no puzzle statement for day {} part {}", day, part))?;
+
+        if json {
+            println!("{}", statement_to_json(&statement));
+        } else {
+            print_statement(&statement);
+        }
+    }
+
+    Ok(())
+}
+
+fn print_statement(statement: &Statement) {
+    println!("--- Day {}: {}, part {} ---\n", statement.day, statement.title, statement.part);
+    println!("{}\n", statement.text);
+
+    for (index, example) in statement.examples.iter().enumerate() {
+        match &example.answer {
+            Some(answer) => println!("example {}, answer {}:", index + 1, answer),
+            None => println!("example {}, answer not given:", index + 1)
+        }
+
+        for line in example.input.lines() {
+            println!("    {}", line);
+        }
+
+        println!();
+    }
+}
+
+fn statement_to_json(statement: &Statement) -> Value {
+    let examples = statement.examples.iter()
+        .map(|example| Value::object(vec![
+            ("input", Value::from(example.input.as_str())),
+            ("answer", Value::from(example.answer.as_deref())),
+        ]))
+        .collect();
+
+    Value::object(vec![
+        ("day", Value::number(statement.day)),
+        ("part", Value::number(statement.part)),
+        ("title", Value::from(statement.title)),
+        ("text", Value::from(statement.text)),
+        ("examples", Value::Array(examples)),
+    ])
+}
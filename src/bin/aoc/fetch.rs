@@ -0,0 +1,39 @@
+use std::env;
+use std::path::PathBuf;
+
+use advent_of_code_2020::fetch::{self, Fetched, Fetcher};
+
+use crate::args::Args;
+
+// downloads the input of a day into the cache, unless it is already there
+pub fn fetch(args: &Args) -> Result<(), String> {
+    let day = match args.positional(1) {
+        Some(day) => crate::parse_number(day, "day")?,
+        None => return Err("no day given".to_owned())
+    };
+
+    let session = args.option("session").map(str::to_owned)
+        .or_else(|| env::var("AOC_SESSION").ok())
+        .map(|session| session.trim().to_owned())
+        .filter(|session| !session.is_empty())
+        .ok_or("no session token given, use --session or set AOC_SESSION")?;
+
+    let base_url = args.option("base-url").map(str::to_owned)
+        .or_else(|| env::var("AOC_BASE_URL").ok())
+        .unwrap_or_else(|| fetch::DEFAULT_BASE_URL.to_owned());
+
+    let cache_dir = args.option("cache-dir").map(PathBuf::from)
+        .or_else(fetch::default_cache_dir)
+        .ok_or("no cache directory, use --cache-dir or set AOC_CACHE_DIR")?;
+
+    let fetcher = Fetcher { base_url, session, cache_dir };
+
+    match fetcher.fetch(day)? {
+        Fetched::Cached(path) => {
+            println!("already cached, not downloading again: {}", path.display());
+        }
+        Fetched::Downloaded(path) => println!("wrote {}", path.display())
+    }
+
+    Ok(())
+}
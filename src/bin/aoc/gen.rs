@@ -0,0 +1,28 @@
+use std::io::{self, Write};
+
+use advent_of_code_2020::generate;
+
+use crate::args::Args;
+
+// prints a generated input, which can be piped into `aoc run` or `aoc bench`
+pub fn gen(args: &Args) -> Result<(), String> {
+    let day = match args.positional(1) {
+        Some(day) => crate::parse_number(day, "day")?,
+        None => return Err("no day given".to_owned())
+    };
+
+    let size = match args.option("size") {
+        Some(size) => Some(size.parse::<usize>().map_err(|_| format!("invalid size: {}", size))?),
+        None => None
+    };
+
+    let seed = match args.option("seed") {
+        Some(seed) => seed.parse::<u64>().map_err(|_| format!("invalid seed: {}", seed))?,
+        None => 0
+    };
+
+    let input = generate::generate(day, size, seed)?;
+
+    io::stdout().write_all(input.as_bytes())
+        .map_err(|e| format!("failed to write input: {}", e))
+}
@@ -0,0 +1,507 @@
+use std::io;
+use std::time::{Duration, Instant};
+
+use advent_of_code_2020::input;
+use advent_of_code_2020::json::Value;
+use advent_of_code_2020::pool;
+use advent_of_code_2020::registry::{self, Run, Solver};
+use advent_of_code_2020::statement;
+use advent_of_code_2020::trace::{Frames, Observer, StepCounter, Verbose};
+
+mod args;
+mod audit;
+mod bench;
+mod describe;
+mod fetch;
+mod gen;
+mod new;
+mod sum;
+mod verify;
+
+use args::Args;
+
+const USAGE: &str = "\
+usage:
+    aoc list
+    aoc run <day> [part] [--variant <name>] [--input <path> | -] [--jobs <n>]
+            [--format text|json]
+    aoc run <day> [part] --example [--variant <name>] [--format text|json]
+    aoc run <day> [part] --trace verbose|steps|frames [--input <path> | -]
+    aoc run all [--example | --jobs <n>] [--format text|json]
+    aoc describe <day> [part] [--format text|json]
+    aoc verify [--answers <path>]
+    aoc bench <day> [part] [--variant <name>] [--input <path> | -]
+              [--warmup <n>] [--iterations <n>] [--format text|csv]
+    aoc gen <day> [--size <n>] [--seed <n>]
+    aoc new <day> <slug> [--title <title>] [--root <path>]
+    aoc fetch <day> [--session <token>] [--base-url <url>] [--cache-dir <path>]
+    aoc sum <k> [--target <n>] [--all | --count] [--input <path> | -]
+            [--format text|json]
+    aoc sum any [--target <n>] [--input <path> | -] [--format text|json]
+    aoc audit [policy [other policy]] [--graphemes] [--input <path> | -]
+              [--format text|csv|json]
+
+run all runs the default solver of every part; the other variants of a part,
+such as the bigint ones of day 1, run with --variant, e.g.
+`aoc run 1 --variant bigint`.
+
+--input reads the puzzle input from a file, or from stdin if it is -; the
+bundled input of each day is used otherwise. --format json prints one JSON
+object per line for each solver run.
+
+--jobs runs the solvers on that many threads at once, and lists them from the
+slowest, with the wall time of the whole run and the CPU time of all solvers.
+
+--trace shows how the solvers of days 11, 12 and 13 iterate towards the answer:
+verbose prints every step as it happens, steps counts them and frames prints the
+state after each step, such as the seat layout of day 11.
+
+--example runs each part on the examples of its puzzle statement instead, next
+to the answer the statement gives for each, if it does.
+
+describe prints the puzzle statement of a part, with the examples it gives and
+their answers.
+
+verify runs every solver against the answers recorded in answers.txt.
+
+bench times parsing and solving separately over many iterations, for every
+variant of the part unless --variant is given.
+
+gen prints a random but valid input for a day, the same for the same seed
+(0 by default), about as large as the bundled input unless --size is given.
+Pipe it into run or bench to use it, e.g. `aoc gen 10 --size 5000 | aoc bench 10 -`.
+
+new creates the modules, an empty input and a binary for each part of a new
+day, e.g. `aoc new 14 docking-data`, and registers it with the runner. The
+title defaults to the slug in title case.
+
+fetch downloads the input of a day into the cache, once: a cached input is
+never downloaded again, delete it to do so. The session token is the session
+cookie of a logged in browser, from --session or AOC_SESSION. The base URL
+defaults to https://adventofcode.com/2020, or AOC_BASE_URL, and https needs
+curl. The cache is in AOC_CACHE_DIR, or advent-of-code-2020 under
+XDG_CACHE_HOME or ~/.cache, in a directory of its own for each session and base
+URL, since inputs differ by account. fetch prints the path of the input, to run
+it with e.g. `aoc run 7 --input <path>`.
+
+sum finds k entries of the day 1 expense report which sum to --target, 2020 by
+default, and prints the lines they are on; 2 and 3 are the two parts of day 1.
+For more than 3 entries it meets in the middle, which needs memory for every
+combination of k / 2 entries. --all lists every combination of values which
+sums to the target, once however many lines repeat them, and --count only
+counts them, which shows whether a report is ambiguous. `aoc sum any` finds
+the fewest entries of any number which sum to the target instead, keeping the
+sums of each number of entries in bitsets, so the entries and the target have
+to fit in 64 bits and the sums can't span much more than 8 million values.
+
+audit checks the passwords of a day 2 database against a policy: sled and
+toboggan are those of the two parts, exactly-one, distinct and forbidden read
+the numbers and the letter in other ways. It lists every invalid entry with its
+line and why it's invalid, or with two policies, every entry which is valid
+under one but not the other. Without a policy it counts the valid passwords
+under each, and describes how each reads the numbers and the letter. The
+positions and counts are of chars, as in the puzzle, unless --graphemes counts
+grapheme clusters instead, so that an accented letter is one character however
+it's encoded. An entry whose letter is more than one char is always counted in
+grapheme clusters, since it could never match a single char.";
+
+fn main() {
+    if let Err(message) = run(std::env::args().skip(1)) {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    }
+}
+
+// the options and flags each command takes, and how many positional arguments
+// can follow it, counting a lone - for stdin
+struct Command {
+    name: &'static str,
+    options: &'static [&'static str],
+    flags: &'static [&'static str],
+    positional: usize
+}
+
+const COMMANDS: &[Command] = &[
+    Command { name: "list", options: &[], flags: &[], positional: 0 },
+    Command {
+        name: "run",
+        options: &["variant", "input", "jobs", "format", "trace"],
+        flags: &["example"],
+        positional: 3
+    },
+    Command { name: "describe", options: &["format"], flags: &[], positional: 2 },
+    Command { name: "verify", options: &["answers"], flags: &[], positional: 0 },
+    Command {
+        name: "bench",
+        options: &["variant", "input", "warmup", "iterations", "format"],
+        flags: &[],
+        positional: 3
+    },
+    Command { name: "gen", options: &["size", "seed"], flags: &[], positional: 1 },
+    Command { name: "new", options: &["title", "root"], flags: &[], positional: 2 },
+    Command { name: "fetch", options: &["session", "base-url", "cache-dir"], flags: &[], positional: 1 },
+    Command { name: "sum", options: &["target", "input", "format"], flags: &["all", "count"], positional: 2 },
+    Command { name: "audit", options: &["input", "format"], flags: &["graphemes"], positional: 3 }
+];
+
+fn run<I>(args: I) -> Result<(), String>
+where I: Iterator<Item = String> {
+    let options = COMMANDS.iter().flat_map(|command| command.options.iter().copied()).collect::<Vec<_>>();
+    let flags = COMMANDS.iter().flat_map(|command| command.flags.iter().copied()).collect::<Vec<_>>();
+
+    let args = Args::parse(args, &options, &flags)?;
+
+    let command = match args.positional(0) {
+        Some(name) => COMMANDS.iter()
+            .find(|command| command.name == name)
+            .ok_or_else(|| format!("unknown command: {}\n\n{}", name, USAGE))?,
+        None => return Err(format!("no command given\n\n{}", USAGE))
+    };
+
+    if let Some(name) = args.names().find(|name| !command.options.contains(name) && !command.flags.contains(name)) {
+        return Err(format!("{} doesn't take --{}", command.name, name));
+    }
+
+    if let Some(arg) = args.positional(command.positional + 1) {
+        return Err(format!("unexpected argument for {}: {}", command.name, arg));
+    }
+
+    match command.name {
+        "list" => list(),
+        "run" => run_solvers(&args),
+        "describe" => describe::describe(&args),
+        "verify" => verify::verify(&args),
+        "bench" => bench::bench(&args),
+        "gen" => gen::gen(&args),
+        "new" => new::new(&args),
+        "fetch" => fetch::fetch(&args),
+        "sum" => sum::sum(&args),
+        _ => audit::audit(&args)
+    }
+}
+
+fn list() -> Result<(), String> {
+    println!("{:>3}  {:>4}  {:<10}  title", "day", "part", "variant");
+
+    for solver in registry::SOLVERS {
+        println!(
+            "{:>3}  {:>4}  {:<10}  {}",
+            solver.day, solver.part, solver.variant.unwrap_or("-"), solver.title()
+        );
+    }
+
+    Ok(())
+}
+
+fn run_solvers(args: &Args) -> Result<(), String> {
+    let solvers = select_solvers(args, false)?;
+
+    let json = match args.option("format") {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(format) => return Err(format!("unknown format: {}", format))
+    };
+
+    let jobs = match args.option("jobs") {
+        Some(jobs) => match parse_number(jobs, "number of jobs")? {
+            0 => return Err("the number of jobs must be at least 1".to_owned()),
+            jobs => Some(jobs as usize)
+        },
+        None => None
+    };
+
+    if args.flag("example") {
+        if jobs.is_some() {
+            return Err("--jobs can't be used with --example".to_owned());
+        }
+
+        return run_examples(args, &solvers, json);
+    }
+
+    let input = read_input(args, &solvers)?;
+
+    if let Some(observer) = args.option("trace") {
+        if jobs.is_some() || json {
+            return Err("--trace can't be used with --jobs or --format".to_owned());
+        }
+
+        return run_traced(&solvers, input.as_deref(), observer);
+    }
+
+    if let Some(jobs) = jobs {
+        return run_parallel(&solvers, input.as_deref(), jobs, json);
+    }
+
+    if json {
+        for solver in &solvers {
+            let run = run_solver(solver, input.as_deref().unwrap_or(solver.bundled_input()), true)?;
+            println!("{}", run_to_json(solver, &run));
+        }
+
+        return Ok(());
+    }
+
+    println!("{:>3}  {:>4}  {:<10}  {:<20}  {:>20}  {:>12}", "day", "part", "variant", "title", "answer", "time");
+
+    let mut total = Duration::default();
+
+    for solver in &solvers {
+        let run = run_solver(solver, input.as_deref().unwrap_or(solver.bundled_input()), false)?;
+        let elapsed = run.elapsed();
+
+        total += elapsed;
+
+        println!(
+            "{:>3}  {:>4}  {:<10}  {:<20}  {:>20}  {:>12}",
+            solver.day, solver.part, solver.variant.unwrap_or("-"), solver.title(),
+            run.answer.as_deref().unwrap_or("no solution"), format!("{:.2?}", elapsed)
+        );
+    }
+
+    if solvers.len() > 1 {
+        println!("{} solvers, total time: {:.2?}", solvers.len(), total);
+    }
+
+    Ok(())
+}
+
+// runs the solvers on a pool of threads, listing them from the slowest; the CPU
+// time is the time spent in the solvers, added up across threads
+fn run_parallel(solvers: &[&Solver], input: Option<&str>, jobs: usize, json: bool) -> Result<(), String> {
+    let start = Instant::now();
+    let runs = pool::map(solvers, jobs, |solver| run_solver(solver, input.unwrap_or(solver.bundled_input()), json));
+    let wall_time = start.elapsed();
+
+    let mut runs = solvers.iter().zip(runs)
+        .map(|(solver, run)| run.map(|run| (solver, run)))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if json {
+        for (solver, run) in &runs {
+            println!("{}", run_to_json(solver, run));
+        }
+
+        return Ok(());
+    }
+
+    runs.sort_by_key(|(_, run)| std::cmp::Reverse(run.elapsed()));
+
+    let cpu_time = runs.iter().map(|(_, run)| run.elapsed()).sum::<Duration>();
+
+    println!(
+        "{:>3}  {:>4}  {:<10}  {:<20}  {:>20}  {:>12}  {:>6}",
+        "day", "part", "variant", "title", "answer", "time", "share"
+    );
+
+    for (solver, run) in &runs {
+        let share = run.elapsed().as_secs_f64() / cpu_time.as_secs_f64().max(f64::MIN_POSITIVE);
+
+        println!(
+            "{:>3}  {:>4}  {:<10}  {:<20}  {:>20}  {:>12}  {:>5.1}%",
+            solver.day, solver.part, solver.variant.unwrap_or("-"), solver.title(),
+            run.answer.as_deref().unwrap_or("no solution"), format!("{:.2?}", run.elapsed()), share * 100.0
+        );
+    }
+
+    println!(
+        "{} solvers on {} threads, wall time: {:.2?}, cpu time: {:.2?}",
+        runs.len(), jobs.min(runs.len()), wall_time, cpu_time
+    );
+
+    Ok(())
+}
+
+fn run_traced(solvers: &[&Solver], input: Option<&str>, observer: &str) -> Result<(), String> {
+    if !["verbose", "steps", "frames"].contains(&observer) {
+        return Err(format!("unknown trace: {}, expected verbose, steps or frames", observer));
+    }
+
+    // only some parts iterate, e.g. part 2 of day 13
+    let tracers = solvers.iter()
+        .filter(|solver| solver.variant.is_none())
+        .filter_map(|solver| registry::tracer(solver.day, solver.part).map(|tracer| (solver, tracer)))
+        .collect::<Vec<_>>();
+
+    if tracers.is_empty() {
+        return Err("no solver that can be traced, only days 11, 12 and 13 can".to_owned());
+    }
+
+    for (solver, tracer) in tracers {
+        let trace = |observer: &mut dyn Observer| {
+            (tracer.trace)(input.unwrap_or(solver.bundled_input()), observer)
+                .map_err(|e| format!("invalid input for {}\n{}", solver.label(), e.render()))
+        };
+
+        println!("--- {} {} ---", solver.label(), solver.title());
+
+        let answer = match observer {
+            "verbose" => trace(&mut Verbose::new(io::stdout()))?,
+            "steps" => {
+                let mut counter = StepCounter::default();
+                let answer = trace(&mut counter)?;
+
+                println!("{} steps", counter.steps);
+                answer
+            }
+            _ => {
+                let mut frames = Frames::default();
+                let answer = trace(&mut frames)?;
+
+                for (index, frame) in frames.frames.iter().enumerate() {
+                    println!("frame {}/{}:\n{}", index + 1, frames.frames.len(), frame.trim_end());
+                }
+
+                answer
+            }
+        };
+
+        println!("answer: {}", answer.as_deref().unwrap_or("no solution"));
+    }
+
+    Ok(())
+}
+
+fn run_examples(args: &Args, solvers: &[&Solver], json: bool) -> Result<(), String> {
+    if input_path(args).is_some() {
+        return Err("--example can't be used with an input".to_owned());
+    }
+
+    if !json {
+        println!(
+            "{:>3}  {:>4}  {:<10}  {:>7}  {:>20}  {:>20}  {:>12}",
+            "day", "part", "variant", "example", "answer", "expected", "time"
+        );
+    }
+
+    let mut examples = 0;
+
+    for solver in solvers {
+        for (index, example) in statement::examples(solver.day, solver.part).iter().enumerate() {
+            let run = solver.run_example(&example.input)
+                .map_err(|e| format!("invalid example for {}\n{}", solver.label(), e.render()))?;
+
+            examples += 1;
+
+            if json {
+                let mut value = run_to_json(solver, &run);
+
+                if let Value::Object(fields) = &mut value {
+                    fields.push(("example".to_owned(), Value::number(index + 1)));
+                    fields.push(("expected".to_owned(), Value::from(example.answer.as_deref())));
+                }
+
+                println!("{}", value);
+                continue;
+            }
+
+            println!(
+                "{:>3}  {:>4}  {:<10}  {:>7}  {:>20}  {:>20}  {:>12}",
+                solver.day, solver.part, solver.variant.unwrap_or("-"), index + 1,
+                run.answer.as_deref().unwrap_or("no solution"), example.answer.as_deref().unwrap_or("-"),
+                format!("{:.2?}", run.elapsed())
+            );
+        }
+    }
+
+    if examples == 0 {
+        return Err("the puzzle statement has no examples".to_owned());
+    }
+
+    Ok(())
+}
+
+// fails with a diagnostic pointing at the malformed part of the input; only
+// JSON shows the details, so they're only worked out for it
+fn run_solver(solver: &Solver, input: &str, json: bool) -> Result<Run, String> {
+    let run = if json { solver.detailed } else { solver.run };
+
+    run(input)
+        .map_err(|e| format!("invalid input for {}\n{}", solver.label(), e.render()))
+}
+
+fn run_to_json(solver: &Solver, run: &Run) -> Value {
+    let details = run.details.iter()
+        .map(|(name, value)| (*name, Value::from(value.as_str())));
+
+    Value::object(vec![
+        ("day", Value::number(solver.day)),
+        ("part", Value::number(solver.part)),
+        ("variant", Value::from(solver.variant)),
+        ("title", Value::from(solver.title())),
+        ("answer", Value::from(run.answer.as_deref())),
+        ("details", Value::object(details)),
+        ("parse_time_ns", Value::number(run.parse_time.as_nanos())),
+        ("solve_time_ns", Value::number(run.solve_time.as_nanos())),
+        ("elapsed_ns", Value::number(run.elapsed().as_nanos())),
+    ])
+}
+
+// selects the default solver of each part, or every variant if all_variants is
+// set and no variant is given, of one day or of all of them
+fn select_solvers(args: &Args, all_variants: bool) -> Result<Vec<&'static Solver>, String> {
+    let variant = args.option("variant");
+
+    let day = match args.positional(1) {
+        Some("all") if input_path(args).is_some() => {
+            return Err("an input can only be given when running a single day".to_owned())
+        }
+        Some("all") if variant.is_some() => {
+            return Err("a variant can only be given when running a single day".to_owned())
+        }
+        Some("all") => {
+            return Ok(registry::SOLVERS.iter().filter(|s| all_variants || s.variant.is_none()).collect())
+        }
+        Some(day) => parse_number(day, "day")?,
+        None => return Err("no day given".to_owned())
+    };
+
+    let parts = match args.positional(2) {
+        Some("-") | None => vec![1, 2],
+        Some(part) => vec![parse_part(part)?]
+    };
+
+    let solvers = registry::SOLVERS.iter()
+        .filter(|s| s.day == day && parts.contains(&s.part))
+        .filter(|s| s.variant == variant || (all_variants && variant.is_none()))
+        .collect::<Vec<_>>();
+
+    if solvers.is_empty() {
+        return Err(match variant {
+            Some(variant) => format!("no solver for day {} with variant {}", day, variant),
+            None => format!("no solver for day {}", day)
+        });
+    }
+
+    Ok(solvers)
+}
+
+// the path given by --input or a lone -
+fn input_path(args: &Args) -> Option<&str> {
+    args.option("input")
+        .or_else(|| args.positional.iter().skip(1).find(|arg| *arg == "-").map(|s| s as &str))
+}
+
+// returns None if each solver should use its bundled input
+fn read_input(args: &Args, solvers: &[&Solver]) -> Result<Option<String>, String> {
+    let path = match input_path(args) {
+        Some(path) => path,
+        None => return Ok(None)
+    };
+
+    let bundled = solvers.first().map(|solver| solver.bundled_input()).unwrap_or("");
+
+    input::read(Some(path), bundled)
+        .map(Some)
+        .map_err(|e| format!("failed to read {}: {}", path, e))
+}
+
+fn parse_number(s: &str, name: &str) -> Result<u32, String> {
+    s.parse::<u32>().map_err(|_| format!("invalid {}: {}", name, s))
+}
+
+fn parse_part(s: &str) -> Result<u32, String> {
+    match parse_number(s, "part")? {
+        part @ 1..=2 => Ok(part),
+        _ => Err(format!("invalid part: {}, expected 1 or 2", s))
+    }
+}
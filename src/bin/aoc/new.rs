@@ -0,0 +1,26 @@
+use std::path::Path;
+
+use advent_of_code_2020::scaffold::{self, Scaffold};
+
+use crate::args::Args;
+
+// creates the modules, input and binaries of a new day, and registers it
+pub fn new(args: &Args) -> Result<(), String> {
+    let day = match args.positional(1) {
+        Some(day) => crate::parse_number(day, "day")?,
+        None => return Err("no day given".to_owned())
+    };
+
+    let slug = args.positional(2).ok_or("no slug given, e.g. handy-haversacks")?;
+    let root = Path::new(args.option("root").unwrap_or(scaffold::ROOT));
+
+    let scaffold = Scaffold::new(day, slug, args.option("title"))?;
+
+    for path in scaffold.create(root)? {
+        println!("wrote {}", path.display());
+    }
+
+    println!("day {}: {}; record its answers in answers.txt once solved", day, scaffold.title);
+
+    Ok(())
+}
@@ -0,0 +1,177 @@
+use std::str::FromStr;
+
+use advent_of_code_2020::bigint::BigInt;
+use advent_of_code_2020::day01::{self, subset_sum, sums::{self, Entry}};
+use advent_of_code_2020::input;
+use advent_of_code_2020::json::Value;
+use advent_of_code_2020::parse::{numbered_lines, parse_integers};
+
+use crate::args::Args;
+
+// finds k entries of an expense report which sum to a target, 2020 unless
+// --target is given, and prints the lines they are on; or with --all, every
+// distinct combination that does, or with --count only how many there are.
+// The entries and the target may be integers of any size. With any instead of
+// k, finds the fewest entries of any number which sum to the target.
+pub fn sum(args: &Args) -> Result<(), String> {
+    let k = match args.positional(1) {
+        Some("any") => return subset(args),
+        Some(k) => crate::parse_number(k, "number of entries")? as usize,
+        None => return Err("no number of entries given".to_owned())
+    };
+
+    let target = match args.option("target") {
+        Some(target) => target.parse::<BigInt>().map_err(|_| format!("invalid target: {}", target))?,
+        None => BigInt::from(day01::TARGET)
+    };
+
+    let json = json(args)?;
+    let (numbers, lines) = read_report::<BigInt>(args, "an integer")?;
+
+    if args.flag("all") && args.flag("count") {
+        return Err("--all and --count can't be used together".to_owned());
+    }
+
+    if args.flag("count") {
+        let count = sums::count_k_sums(&numbers, k, target.clone());
+
+        if json {
+            println!("{}", Value::object(vec![
+                ("k", Value::number(k)),
+                ("target", Value::number(&target)),
+                ("count", Value::number(count)),
+            ]));
+        } else {
+            println!("{} combinations of {} entries sum to {}", count, k, target);
+        }
+
+        return Ok(());
+    }
+
+    if args.flag("all") {
+        let all = sums::all_k_sums(&numbers, k, target.clone());
+
+        if json {
+            for entries in &all {
+                println!("{}", sum_to_json(Some(k), &target, Some(entries), &lines));
+            }
+        } else {
+            print_all(k, &target, &all, &lines);
+        }
+
+        return Ok(());
+    }
+
+    let entries = day01::find_k_sum(&numbers, k, target.clone());
+
+    if json {
+        println!("{}", sum_to_json(Some(k), &target, entries.as_deref(), &lines));
+    } else {
+        print_sum(k, &target, entries.as_deref(), &lines);
+    }
+
+    Ok(())
+}
+
+// the fewest entries which sum to the target, which have to fit in an i64 for
+// the search to track their sums in bitsets
+fn subset(args: &Args) -> Result<(), String> {
+    let target = match args.option("target") {
+        Some(target) => target.parse::<i64>().map_err(|_| format!("invalid target: {}", target))?,
+        None => i64::from(day01::TARGET)
+    };
+
+    if args.flag("all") || args.flag("count") {
+        return Err("--all and --count need a number of entries".to_owned());
+    }
+
+    let json = json(args)?;
+    let (numbers, lines) = read_report::<i64>(args, "a 64-bit integer")?;
+
+    let entries = subset_sum::min_subset_sum(&numbers, target)
+        .map_err(|e| e.to_string())?
+        .map(|entries| entries.into_iter().map(|(index, value)| (index, BigInt::from(value))).collect::<Vec<_>>());
+
+    let target = BigInt::from(target);
+    let k = entries.as_ref().map(|entries| entries.len());
+
+    match (json, k) {
+        (true, _) => println!("{}", sum_to_json(k, &target, entries.as_deref(), &lines)),
+        (false, Some(k)) => print_sum(k, &target, entries.as_deref(), &lines),
+        (false, None) => println!("no entries sum to {}", target)
+    }
+
+    Ok(())
+}
+
+fn json(args: &Args) -> Result<bool, String> {
+    match args.option("format") {
+        None | Some("text") => Ok(false),
+        Some("json") => Ok(true),
+        Some(format) => Err(format!("unknown format: {}", format))
+    }
+}
+
+// the entries of the report, and the line each is on, as blank lines are
+// skipped
+fn read_report<T: FromStr>(args: &Args, expected: &str) -> Result<(Vec<T>, Vec<usize>), String> {
+    let path = crate::input_path(args);
+    let report = input::read(path, day01::INPUT)
+        .map_err(|e| format!("failed to read {}: {}", path.unwrap_or("-"), e))?;
+
+    let numbers = parse_integers::<T>(&report, expected)
+        .map_err(|e| format!("invalid expense report\n{}", e.render()))?;
+
+    let lines = numbered_lines(&report)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, _)| number)
+        .collect();
+
+    Ok((numbers, lines))
+}
+
+fn product(entries: &[Entry<BigInt>]) -> BigInt {
+    entries.iter().fold(BigInt::from(1), |product, (_, value)| product * value.clone())
+}
+
+fn print_sum(k: usize, target: &BigInt, entries: Option<&[Entry<BigInt>]>, lines: &[usize]) {
+    let entries = match entries {
+        Some(entries) => entries,
+        None => return println!("no {} entries sum to {}", k, target)
+    };
+
+    for (index, value) in entries {
+        println!("line {}: {}", lines[*index], value);
+    }
+
+    println!("{} entries sum to {}, their product is {}", k, target, product(entries));
+}
+
+fn print_all(k: usize, target: &BigInt, all: &[Vec<Entry<BigInt>>], lines: &[usize]) {
+    for entries in all {
+        let entry_lines = entries.iter().map(|(index, _)| lines[*index].to_string()).collect::<Vec<_>>();
+        let values = entries.iter().map(|(_, value)| value.to_string()).collect::<Vec<_>>();
+
+        println!("lines {}: {}, product {}", entry_lines.join(", "), values.join(" + "), product(entries));
+    }
+
+    println!("{} combinations of {} entries sum to {}", all.len(), k, target);
+}
+
+fn sum_to_json(k: Option<usize>, target: &BigInt, entries: Option<&[Entry<BigInt>]>, lines: &[usize]) -> Value {
+    let entry_values = entries.map(|entries| {
+        Value::Array(entries.iter()
+            .map(|(index, value)| Value::object(vec![
+                ("line", Value::number(lines[*index])),
+                ("value", Value::number(value)),
+            ]))
+            .collect())
+    });
+
+    Value::object(vec![
+        ("k", k.map(Value::number).unwrap_or(Value::Null)),
+        ("target", Value::number(target)),
+        ("entries", entry_values.unwrap_or(Value::Null)),
+        ("product", entries.map(product).map(Value::number).unwrap_or(Value::Null)),
+    ])
+}
@@ -0,0 +1,91 @@
+use std::path::Path;
+use std::time::Duration;
+
+use advent_of_code_2020::answers::{self, Answers, BUNDLED};
+use advent_of_code_2020::input;
+use advent_of_code_2020::registry;
+
+use crate::args::Args;
+
+// runs every solver against every input it has a known answer for, as well as
+// its bundled input; fails if any answer doesn't match or any input is invalid
+pub fn verify(args: &Args) -> Result<(), String> {
+    let path = args.option("answers").unwrap_or(answers::DEFAULT_PATH);
+    let answers = Answers::load(Path::new(path))?;
+
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let mut total = Duration::default();
+
+    println!("{:>3}  {:>4}  {:<10}  {:<20}  {:<8}  {:>12}  result", "day", "part", "variant", "input", "status", "time");
+
+    for solver in registry::SOLVERS {
+        let mut inputs = answers.for_part(solver.day, solver.part)
+            .map(|known| (known.input.as_str(), Some(known)))
+            .collect::<Vec<_>>();
+
+        if !inputs.iter().any(|&(name, _)| name == BUNDLED) {
+            inputs.insert(0, (BUNDLED, None));
+        }
+
+        for (name, known) in inputs {
+            let path = known.and_then(|known| answers.input_path(known));
+            let input = match &path {
+                Some(path) => input::read(path.to_str(), solver.bundled_input())
+                    .map_err(|e| format!("failed to read {}: {}", path.display(), e))?,
+                None => solver.bundled_input().to_owned()
+            };
+
+            let run = match (solver.run)(&input) {
+                Ok(run) => run,
+                Err(e) => {
+                    failed += 1;
+                    println!(
+                        "{:>3}  {:>4}  {:<10}  {:<20}  {:<8}  {:>12}  {}",
+                        solver.day, solver.part, solver.variant.unwrap_or("-"), name, "INVALID", "-", e
+                    );
+                    continue;
+                }
+            };
+
+            let elapsed = run.elapsed();
+
+            total += elapsed;
+
+            let (status, result) = match (known, run.answer) {
+                (Some(known), Some(answer)) if known.answer == answer => {
+                    passed += 1;
+                    ("ok", answer)
+                }
+                (Some(known), Some(answer)) => {
+                    failed += 1;
+                    ("MISMATCH", format!("expected {}, got {}", known.answer, answer))
+                }
+                (Some(known), None) => {
+                    failed += 1;
+                    ("MISMATCH", format!("expected {}, got no solution", known.answer))
+                }
+                (None, answer) => {
+                    missing += 1;
+                    ("missing", answer.unwrap_or_else(|| "no solution".to_owned()))
+                }
+            };
+
+            println!(
+                "{:>3}  {:>4}  {:<10}  {:<20}  {:<8}  {:>12}  {}",
+                solver.day, solver.part, solver.variant.unwrap_or("-"), name,
+                status, format!("{:.2?}", elapsed), result
+            );
+        }
+    }
+
+    println!(
+        "{} passed, {} failed, {} missing answers; total time: {:.2?}",
+        passed, failed, missing, total
+    );
+
+    if failed > 0 {
+        Err(format!("{} answers did not match", failed))
+    } else {
+        Ok(())
+    }
+}
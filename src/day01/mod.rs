@@ -0,0 +1,271 @@
+use std::str::FromStr;
+
+use crate::bigint::BigInt;
+use crate::parse::{parse_integers, ParseError};
+
+pub mod part1;
+pub mod part1_hashed;
+pub mod part2;
+pub mod subset_sum;
+pub mod sums;
+
+pub use part1::part1;
+pub use part2::part2;
+pub use sums::find_k_sum;
+
+pub const INPUT: &str = include_str!("input");
+pub const TARGET: i32 = 2020;
+
+// returns the expense report, sorted
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse_sorted(input, "a 64-bit integer")
+}
+
+// the expense report in its own order, for searches which don't need it sorted
+pub fn parse_unsorted(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse_integers(input, "a 64-bit integer")
+}
+
+// the expense report with entries of any size, sorted
+pub fn parse_big(input: &str) -> Result<Vec<BigInt>, ParseError> {
+    parse_sorted(input, "an integer")
+}
+
+fn parse_sorted<T: FromStr + Ord>(input: &str, expected: &str) -> Result<Vec<T>, ParseError> {
+    let mut numbers = parse_integers(input, expected)?;
+
+    numbers.sort_unstable();
+
+    Ok(numbers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    use crate::property::{check, equal, Integers, Vecs};
+    use crate::registry::SolveError;
+
+    const EXAMPLE: &str = "\
+1721
+979
+366
+299
+675
+1456
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1::find_pair(&parse(EXAMPLE).unwrap(), TARGET), Some((299, 1721)));
+        assert_eq!(part1(EXAMPLE), Ok(Some(514579)));
+        assert_eq!(part1_hashed::part1(EXAMPLE), Ok(Some(514579)));
+    }
+
+    #[test]
+    fn pair_searches_agree() {
+        // small numbers and targets, so that most reports have a pair
+        let reports = (Vecs { element: Integers(-20..60), lengths: 0..12 }, Integers(-10..100));
+
+        check(&reports, |(numbers, target)| {
+            let numbers = numbers.iter().map(|&n| n as i32).collect::<Vec<_>>();
+            let target = *target as i32;
+
+            let mut sorted = numbers.clone();
+            sorted.sort_unstable();
+
+            let two_pointers = part1::find_pair(&sorted, target);
+            let hashed = part1_hashed::find_pair(numbers.iter().copied(), target);
+
+            if two_pointers.is_some() != hashed.is_some() {
+                return Err(format!("two pointers found {:?}, hashed found {:?}", two_pointers, hashed));
+            }
+
+            // the pairs may differ when several sum to target, but each has to
+            // be two of the entries
+            for &(first, second) in two_pointers.iter().chain(hashed.iter()) {
+                let mut rest = numbers.clone();
+                let mut take = |n| rest.iter().position(|&m| m == n).map(|index| rest.swap_remove(index));
+
+                if first + second != target || take(first).is_none() || take(second).is_none() {
+                    return Err(format!("{:?} is not a pair of entries summing to {}", (first, second), target));
+                }
+            }
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EXAMPLE), Ok(Some(241861950)));
+    }
+
+    #[test]
+    fn k_sums() {
+        let numbers = [1721i64, 979, 366, 299, 675, 1456];
+
+        assert_eq!(find_k_sum(&numbers, 2, 2020), Some(vec![(0, 1721), (3, 299)]));
+        assert_eq!(find_k_sum(&numbers, 3, 2020), Some(vec![(1, 979), (2, 366), (4, 675)]));
+        assert_eq!(find_k_sum(&numbers, 1, 675), Some(vec![(4, 675)]));
+        assert_eq!(find_k_sum(&numbers, 0, 0), Some(vec![]));
+
+        // by meeting in the middle
+        assert_eq!(find_k_sum(&numbers, 4, 1721 + 366 + 675 + 1456), Some(vec![(0, 1721), (2, 366), (4, 675), (5, 1456)]));
+        assert_eq!(find_k_sum(&numbers, 6, numbers.iter().map(|&n| i128::from(n)).sum()).map(|e| e.len()), Some(6));
+        assert_eq!(find_k_sum(&numbers, 5, 2020), None);
+        assert_eq!(find_k_sum(&numbers, 7, 0), None);
+
+        // entries are only used once, even if repeated
+        assert_eq!(find_k_sum(&[5i64, 5, 10], 2, 10), Some(vec![(0, 5), (1, 5)]));
+        assert_eq!(find_k_sum(&[5i64, 10], 2, 10), None);
+        assert_eq!(find_k_sum(&[1i64, 1, 1, 1, 2], 4, 4), Some(vec![(0, 1), (1, 1), (2, 1), (3, 1)]));
+
+        // sums which don't fit in an i64
+        assert_eq!(find_k_sum(&[i64::MAX, i64::MAX, 1], 2, 2 * i128::from(i64::MAX)), Some(vec![(0, i64::MAX), (1, i64::MAX)]));
+        assert_eq!(find_k_sum(&[i64::MIN, -1, i64::MAX], 3, -2), Some(vec![(0, i64::MIN), (1, -1), (2, i64::MAX)]));
+    }
+
+    #[test]
+    fn k_sum_searches_agree() {
+        let searches = ((Vecs { element: Integers(-10..30), lengths: 0..10 }, Integers(0..7)), Integers(-20..80));
+
+        check(&searches, |((numbers, k), target)| {
+            let numbers = numbers.iter().map(|&n| n as i32).collect::<Vec<_>>();
+            let k = *k as usize;
+
+            let two_pointers = sums::two_pointers(&numbers, k, *target);
+            let meet_in_the_middle = sums::meet_in_the_middle(&numbers, k, *target);
+
+            if two_pointers.is_some() != meet_in_the_middle.is_some() {
+                return Err(format!("two pointers found {:?}, meeting in the middle {:?}", two_pointers, meet_in_the_middle));
+            }
+
+            for indices in two_pointers.iter().chain(meet_in_the_middle.iter()) {
+                let mut distinct = indices.clone();
+                distinct.sort_unstable();
+                distinct.dedup();
+
+                let sum = indices.iter().map(|&index| i64::from(numbers[index])).sum::<i64>();
+
+                if indices.len() != k || distinct.len() != k || sum != *target {
+                    return Err(format!("{:?} are not {} entries summing to {}", indices, k, target));
+                }
+            }
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn all_k_sums() {
+        let numbers = [5i64, 5, 10, 10, 0, 15];
+
+        // the same values on other lines are the same combination
+        assert_eq!(sums::all_k_sums(&numbers, 2, 15), vec![vec![(0, 5), (2, 10)], vec![(4, 0), (5, 15)]]);
+        assert_eq!(sums::all_k_sums(&numbers, 2, 10), vec![vec![(0, 5), (1, 5)], vec![(2, 10), (4, 0)]]);
+        assert_eq!(sums::all_k_sums(&numbers, 2, 30), Vec::<Vec<_>>::new());
+        assert_eq!(sums::all_k_sums(&numbers, 3, 20), vec![
+            vec![(0, 5), (1, 5), (2, 10)],
+            vec![(0, 5), (4, 0), (5, 15)],
+            vec![(2, 10), (3, 10), (4, 0)]
+        ]);
+
+        assert_eq!(sums::count_k_sums(&numbers, 3, 20), 3);
+        assert_eq!(sums::count_k_sums(&numbers, 6, 45), 1);
+        assert_eq!(sums::count_k_sums(&numbers, 0, 0), 1);
+        assert_eq!(sums::count_k_sums(&parse(INPUT).unwrap(), 2, 2020), 1);
+    }
+
+    #[test]
+    fn counts_agree_with_every_combination() {
+        let searches = ((Vecs { element: Integers(0..8), lengths: 0..9 }, Integers(0..5)), Integers(0..25));
+
+        check(&searches, |((numbers, k), target)| {
+            let numbers = numbers.iter().map(|&n| n as i32).collect::<Vec<_>>();
+            let k = *k as usize;
+
+            // the distinct values of every combination of lines
+            let mut expected = HashSet::new();
+            sums::combinations(numbers.len(), k, |indices| {
+                let mut values = indices.iter().map(|&index| numbers[index]).collect::<Vec<_>>();
+                values.sort_unstable();
+
+                if values.iter().map(|&value| i64::from(value)).sum::<i64>() == *target {
+                    expected.insert(values);
+                }
+
+                false
+            });
+
+            let all = sums::all_k_sums(&numbers, k, *target);
+            let found = all.iter()
+                .map(|entries| entries.iter().map(|&(index, value)| {
+                    assert_eq!(numbers[index], value);
+                    value
+                }).collect::<Vec<_>>())
+                .map(|mut values| { values.sort_unstable(); values })
+                .collect::<HashSet<_>>();
+
+            equal(found.len(), all.len())?;
+            equal(found, expected.clone())?;
+            equal(sums::count_k_sums(&numbers, k, *target), expected.len() as u64)
+        });
+    }
+
+    #[test]
+    fn combinations() {
+        let mut all = vec![];
+        sums::combinations(4, 2, |indices| { all.push(indices.to_vec()); false });
+
+        assert_eq!(all, vec![vec![0, 1], vec![0, 2], vec![0, 3], vec![1, 2], vec![1, 3], vec![2, 3]]);
+        assert!(sums::combinations(4, 2, |indices| indices == [1, 3]));
+        assert!(!sums::combinations(2, 3, |_| true));
+        assert!(sums::combinations(0, 0, |indices| indices.is_empty()));
+    }
+
+    #[test]
+    fn no_solution() {
+        assert_eq!(part1("1\n2\n3"), Ok(None));
+        assert_eq!(part2("1\n2"), Ok(None));
+
+        // more entries than the report has, which would take far too long to
+        // rule out by enumerating the halves of every combination
+        let report = (1..=200).collect::<Vec<i64>>();
+        assert_eq!(sums::find_k_sum(&report, 300, 2020), None);
+        assert_eq!(sums::count_k_sums(&report, 201, 20100), 0);
+        assert!(sums::all_k_sums(&report, 300, 2020).is_empty());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = parse("1721\r\n979\r\n\r\n36x6\r\n").unwrap_err();
+
+        assert_eq!(error.to_string(), "line 4, column 1: expected a 64-bit integer, found `36x6`");
+    }
+
+    #[test]
+    fn overflow() {
+        let report = "4000000000\n-3999997980\n";
+
+        let error = part1(report).unwrap_err();
+        assert_eq!(error.to_string(), "the product of -3999997980 and 4000000000 overflows an i64");
+        assert!(matches!(part1_hashed::part1(report), Err(SolveError::Overflow(_))));
+
+        // adding up the entries would overflow an i64 too
+        let report = "9223372036854775807\n10\n-9223372036854773797\n";
+        assert!(matches!(part2(report), Err(SolveError::Overflow(_))));
+
+        let big = parse_big(report).unwrap();
+        assert_eq!(part2::solve(&big).unwrap().unwrap().to_string(), "-850705917302345973084191137061331291790");
+        assert_eq!(part1::solve(&big), Ok(None));
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(Some(1016964)));
+        assert_eq!(part1_hashed::part1(INPUT), Ok(Some(1016964)));
+        assert_eq!(part2(INPUT), Ok(Some(182588480)));
+    }
+}
@@ -0,0 +1,60 @@
+/* --- Day 1: Report Repair ---
+
+After saving Christmas five years in a row, you've decided to take a vacation at a nice resort on a tropical island. Surely, Christmas will go on without you.
+
+The tropical island has its own currency and is entirely cash-only. The gold coins used there have a little picture of a starfish; the locals just call them stars. None of the currency exchanges seem to have heard of them, but somehow, you'll need to find fifty of these coins by the time you arrive so you can pay the deposit on your room.
+
+To save your vacation, you need to get all fifty stars by December 25th.
+
+Collect stars by solving puzzles. Two puzzles will be made available on each day in the Advent calendar; the second puzzle is unlocked when you complete the first. Each puzzle grants one star. Good luck!
+
+Before you leave, the Elves in accounting just need you to fix your expense report (your puzzle input); apparently, something isn't quite adding up.
+
+Specifically, they need you to find the two entries that sum to 2020 and then multiply those two numbers together.
+
+For example, suppose your expense report contained the following:
+
+1721
+979
+366
+299
+675
+1456
+
+In this list, the two entries that sum to 2020 are 1721 and 299. Multiplying them together produces 1721 * 299 = 514579, so the correct answer is 514579.
+
+Of course, your expense report is much larger. Find the two entries that sum to 2020; what do you get if you multiply them together?
+*/
+use crate::number::{checked_product, Number, Overflow};
+use crate::registry::{Details, SolveError};
+
+use super::sums::count_k_sums;
+use super::{find_k_sum, parse, TARGET};
+
+pub fn part1(input: &str) -> Result<Option<i64>, SolveError> {
+    Ok(solve(&parse(input)?)?)
+}
+
+pub fn solve<T: Number>(numbers: &[T]) -> Result<Option<T>, Overflow> {
+    find_pair(numbers, TARGET)
+        .map(|(front, back)| checked_product(&[front, back]))
+        .transpose()
+}
+
+pub fn details<T: Number>(numbers: &[T]) -> Details {
+    match find_pair(numbers, TARGET) {
+        Some((front, back)) => vec![
+            ("entries", format!("{}, {}", front, back)),
+            // more than 1 if the report is ambiguous
+            ("combinations", count_k_sums(numbers, 2, TARGET.into()).to_string())
+        ],
+        None => vec![]
+    }
+}
+
+// returns the first two entries of sorted_numbers that sum to target
+pub fn find_pair<T: Number>(sorted_numbers: &[T], target: i32) -> Option<(T, T)> {
+    let mut entries = find_k_sum(sorted_numbers, 2, target.into())?.into_iter().map(|(_, value)| value);
+
+    Some((entries.next()?, entries.next()?))
+}
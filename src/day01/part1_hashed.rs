@@ -0,0 +1,51 @@
+/* --- Hashed Variant ---
+Rather than walking the sorted report from both ends, this remembers every entry
+seen so far and looks up the one that would complete the pair, which doesn't
+need the report to be sorted. Compare the two with `aoc bench 1 1`.
+*/
+use crate::number::{checked_product, Number, Overflow};
+use crate::pair_sums::PairSums;
+use crate::registry::{Details, SolveError};
+
+use super::sums::count_k_sums;
+use super::{parse_unsorted, TARGET};
+
+pub fn part1(input: &str) -> Result<Option<i64>, SolveError> {
+    Ok(solve(&parse_unsorted(input)?)?)
+}
+
+pub fn solve<T: Number>(numbers: &[T]) -> Result<Option<T>, Overflow> {
+    find_pair(numbers.iter().cloned(), TARGET)
+        .map(|(first, second)| checked_product(&[first, second]))
+        .transpose()
+}
+
+pub fn details<T: Number>(numbers: &[T]) -> Details {
+    match find_pair(numbers.iter().cloned(), TARGET) {
+        Some((first, second)) => vec![
+            ("entries", format!("{}, {}", first, second)),
+            // more than 1 if the report is ambiguous
+            ("combinations", count_k_sums(numbers, 2, TARGET.into()).to_string())
+        ],
+        None => vec![]
+    }
+}
+
+// returns two entries of numbers that sum to target, the one that comes first
+// in numbers first. The numbers don't need to be sorted or even all in memory,
+// only those already seen are kept.
+pub fn find_pair<T: Number, I>(numbers: I, target: i32) -> Option<(T, T)>
+where I: IntoIterator<Item = T> {
+    let target = T::Wide::from(target);
+    let mut seen = PairSums::new();
+
+    for number in numbers {
+        if let Some(complement) = seen.pair_with(&number, &target) {
+            return Some((complement.clone(), number));
+        }
+
+        seen.insert(number);
+    }
+
+    None
+}
@@ -0,0 +1,41 @@
+/* --- Part Two ---
+
+The Elves in accounting are thankful for your help; one of them even offers you a starfish coin they had left over from a past vacation. They offer you a second one if you can find three numbers in your expense report that meet the same criteria.
+
+Using the above example again, the three entries that sum to 2020 are 979, 366, and 675. Multiplying them together produces the answer, 241861950.
+
+In your expense report, what is the product of the three entries that sum to 2020?
+*/
+use crate::number::{checked_product, Number, Overflow};
+use crate::registry::{Details, SolveError};
+
+use super::sums::count_k_sums;
+use super::{find_k_sum, parse, TARGET};
+
+pub fn part2(input: &str) -> Result<Option<i64>, SolveError> {
+    Ok(solve(&parse(input)?)?)
+}
+
+pub fn solve<T: Number>(numbers: &[T]) -> Result<Option<T>, Overflow> {
+    find_triple(numbers, TARGET)
+        .map(|(first, second, third)| checked_product(&[first, second, third]))
+        .transpose()
+}
+
+pub fn details<T: Number>(numbers: &[T]) -> Details {
+    match find_triple(numbers, TARGET) {
+        Some((first, second, third)) => vec![
+            ("entries", format!("{}, {}, {}", first, second, third)),
+            // more than 1 if the report is ambiguous
+            ("combinations", count_k_sums(numbers, 3, TARGET.into()).to_string())
+        ],
+        None => vec![]
+    }
+}
+
+// returns the first three entries of sorted_numbers that sum to target
+pub fn find_triple<T: Number>(sorted_numbers: &[T], target: i32) -> Option<(T, T, T)> {
+    let mut entries = find_k_sum(sorted_numbers, 3, target.into())?.into_iter().map(|(_, value)| value);
+
+    Some((entries.next()?, entries.next()?, entries.next()?))
+}
@@ -0,0 +1,313 @@
+// Finding the smallest subset of an expense report, of any size, which sums to
+// a target. The sums reachable with each number of entries are kept as
+// bitsets, so adding an entry to every sum at once is a shift and an or over
+// words, and the number of entries is doubled until the target is reached.
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+
+use crate::number::{checked_product, Overflow};
+
+use super::sums::Entry;
+
+// the most (number of entries, sum) pairs a search may keep track of, as each
+// takes 4 bytes for the entry that first reached it
+pub const MAX_SUMS: u128 = 1 << 23;
+
+// a search which would need more memory than MAX_SUMS allows
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct TooManySums {
+    pub sums: u128
+}
+
+impl Display for TooManySums {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the search would keep track of {} sums, more than {}", self.sums, MAX_SUMS)
+    }
+}
+
+impl std::error::Error for TooManySums {}
+
+// returns a subset of numbers with as few entries as possible that sums to
+// target, in the order they appear in numbers, or None if no subset does. The
+// empty subset sums to 0.
+pub fn min_subset_sum(numbers: &[i64], target: i64) -> Result<Option<Vec<Entry<i64>>>, TooManySums> {
+    let range = match SumRange::new(numbers, target) {
+        Some(range) => range,
+        None => return Ok(None)
+    };
+
+    if range.len as u128 > MAX_SUMS {
+        return Err(TooManySums { sums: range.len as u128 });
+    }
+
+    if !reachable(numbers, &range, target) {
+        return Ok(None);
+    }
+
+    // some subset reaches the target, so at most every entry is needed
+    let mut most = 2;
+
+    loop {
+        let most_entries = most.min(numbers.len());
+        let sums = (most_entries as u128 + 1) * range.len as u128;
+
+        if sums > MAX_SUMS {
+            return Err(TooManySums { sums });
+        }
+
+        if let Some(subset) = search(numbers, &range, target, most_entries) {
+            return Ok(Some(subset));
+        }
+
+        most *= 2;
+    }
+}
+
+// the product of the values of a subset
+pub fn product(entries: &[Entry<i64>]) -> Result<i64, Overflow> {
+    checked_product(&entries.iter().map(|&(_, value)| value).collect::<Vec<_>>())
+}
+
+// the sums worth keeping track of, from low up to low + len - 1: anything the
+// entries can add up to, except that with no negative entries a sum above the
+// target can never come back down to it
+struct SumRange {
+    low: i128,
+    len: usize
+}
+
+impl SumRange {
+    // None if the target is out of reach of any subset
+    fn new(numbers: &[i64], target: i64) -> Option<Self> {
+        let low = numbers.iter().filter(|&&n| n < 0).map(|&n| i128::from(n)).sum::<i128>();
+        let mut high = numbers.iter().filter(|&&n| n > 0).map(|&n| i128::from(n)).sum::<i128>();
+
+        if low == 0 {
+            high = high.min(i128::from(target));
+        }
+
+        let target = i128::from(target);
+
+        if target < low || target > high {
+            return None;
+        }
+
+        // saturating, as such a range is refused anyway
+        let len = usize::try_from(high - low + 1).unwrap_or(usize::MAX);
+
+        Some(Self { low, len })
+    }
+
+    fn index(&self, sum: i64) -> usize {
+        (i128::from(sum) - self.low) as usize
+    }
+}
+
+// whether any subset sums to target, with a single bitset of the sums reached
+fn reachable(numbers: &[i64], range: &SumRange, target: i64) -> bool {
+    let mut reached = Bitset::new(range.len);
+    let mut shifted = Bitset::new(range.len);
+    reached.set(range.index(0));
+
+    for &number in numbers.iter().filter(|&&n| n != 0) {
+        reached.shift_into(number, &mut shifted);
+        reached.or_with(&shifted);
+    }
+
+    reached.get(range.index(target))
+}
+
+// the smallest subset of at most most_entries entries which sums to target.
+// reached[c] holds the sums of c entries, and first[c][sum] the index of the
+// entry which first reached the sum as the last of c entries, so the sum of
+// the other c - 1 was reached by entries before it.
+fn search(numbers: &[i64], range: &SumRange, target: i64, most_entries: usize) -> Option<Vec<Entry<i64>>> {
+    let mut reached = vec![Bitset::new(range.len); most_entries + 1];
+    let mut first = vec![vec![u32::MAX; range.len]; most_entries + 1];
+    let mut shifted = Bitset::new(range.len);
+
+    reached[0].set(range.index(0));
+
+    for (index, &number) in numbers.iter().enumerate().filter(|&(_, &n)| n != 0) {
+        // from the most entries down, so that the entry is only added once
+        for count in (1..=most_entries.min(index + 1)).rev() {
+            reached[count - 1].shift_into(number, &mut shifted);
+
+            for sum in reached[count].or_with(&shifted) {
+                first[count][sum] = index as u32;
+            }
+        }
+    }
+
+    let target = range.index(target);
+    let count = (0..=most_entries).find(|&count| reached[count].get(target))?;
+
+    let mut entries = vec![];
+    let mut sum = target;
+
+    for count in (1..=count).rev() {
+        let index = first[count][sum] as usize;
+        entries.push((index, numbers[index]));
+        sum = (sum as i128 - i128::from(numbers[index])) as usize;
+    }
+
+    entries.sort_unstable();
+    Some(entries)
+}
+
+#[derive(Debug, Clone)]
+struct Bitset {
+    words: Vec<u64>,
+    len: usize
+}
+
+impl Bitset {
+    fn new(len: usize) -> Self {
+        Self { words: vec![0; (len + 63) / 64], len }
+    }
+
+    fn get(&self, bit: usize) -> bool {
+        self.words[bit / 64] & (1 << (bit % 64)) != 0
+    }
+
+    fn set(&mut self, bit: usize) {
+        self.words[bit / 64] |= 1 << (bit % 64);
+    }
+
+    // writes the bits moved up by shift places into out, or down if shift is
+    // negative, dropping those moved past either end
+    fn shift_into(&self, shift: i64, out: &mut Bitset) {
+        let words = self.words.len();
+        let distance = shift.unsigned_abs();
+
+        if distance >= self.len as u64 {
+            out.words.iter_mut().for_each(|word| *word = 0);
+            return;
+        }
+
+        let (word_shift, bit_shift) = ((distance / 64) as usize, (distance % 64) as u32);
+
+        // the word at j, or 0 past either end
+        let word = |j: Option<usize>| j.and_then(|j| self.words.get(j)).copied().unwrap_or(0);
+
+        for j in 0..words {
+            out.words[j] = if shift > 0 {
+                let low = word(j.checked_sub(word_shift));
+                let lower = word(j.checked_sub(word_shift + 1));
+
+                if bit_shift == 0 { low } else { (low << bit_shift) | (lower >> (64 - bit_shift)) }
+            } else {
+                let high = word(Some(j + word_shift));
+                let higher = word(Some(j + word_shift + 1));
+
+                if bit_shift == 0 { high } else { (high >> bit_shift) | (higher << (64 - bit_shift)) }
+            };
+        }
+
+        // bits past the end are never set
+        if self.len % 64 != 0 {
+            out.words[words - 1] &= (1 << (self.len % 64)) - 1;
+        }
+    }
+
+    // sets the bits of other in self, returning those which weren't set yet
+    fn or_with(&mut self, other: &Bitset) -> Vec<usize> {
+        let mut new_bits = vec![];
+
+        for (j, (word, &other)) in self.words.iter_mut().zip(&other.words).enumerate() {
+            let mut new = other & !*word;
+            *word |= other;
+
+            while new != 0 {
+                new_bits.push(j * 64 + new.trailing_zeros() as usize);
+                new &= new - 1;
+            }
+        }
+
+        new_bits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day01::sums::combinations;
+    use crate::property::{check, equal, Integers, Vecs};
+
+    #[test]
+    fn smallest_subsets() {
+        let numbers = [1721, 979, 366, 299, 675, 1456];
+        assert_eq!(min_subset_sum(&numbers, 2020), Ok(Some(vec![(0, 1721), (3, 299)])));
+        assert_eq!(min_subset_sum(&numbers, 979 + 366 + 675 + 1456), Ok(Some(vec![(0, 1721), (3, 299), (5, 1456)])));
+        assert_eq!(min_subset_sum(&numbers, 1), Ok(None));
+        assert_eq!(min_subset_sum(&numbers, 0), Ok(Some(vec![])));
+
+        // fewer entries beat earlier ones
+        assert_eq!(min_subset_sum(&[1, 2, 3, 4, 10], 10), Ok(Some(vec![(4, 10)])));
+        assert_eq!(min_subset_sum(&[1; 200], 200).unwrap().map(|subset| subset.len()), Some(200));
+
+        // negative entries, and sums above the target on the way to it
+        assert_eq!(min_subset_sum(&[3000, 5, -980, -3000], 2020), Ok(Some(vec![(0, 3000), (2, -980)])));
+        assert_eq!(min_subset_sum(&[-5, -7], -12), Ok(Some(vec![(0, -5), (1, -7)])));
+        assert_eq!(min_subset_sum(&[5, 7], -12), Ok(None));
+
+        assert_eq!(product(&[(0, 1721), (3, 299)]), Ok(514579));
+        assert!(product(&[(0, i64::MAX), (1, 2)]).is_err());
+    }
+
+    #[test]
+    fn too_many_sums() {
+        assert_eq!(min_subset_sum(&[i64::MAX, -1], 5), Err(TooManySums { sums: i64::MAX as u128 + 2 }));
+        assert!(min_subset_sum(&[1 << 22, 1 << 21, 1 << 20], 7 << 20).is_err());
+    }
+
+    #[test]
+    fn shifts() {
+        let mut bits = Bitset::new(130);
+        let mut shifted = Bitset::new(130);
+        bits.set(0);
+        bits.set(63);
+        bits.set(129);
+
+        let set = |bits: &Bitset| (0..bits.len).filter(|&bit| bits.get(bit)).collect::<Vec<_>>();
+
+        for &(shift, expected) in &[(1, &[1, 64][..]), (64, &[64, 127]), (-63, &[0, 66]), (-129, &[0]), (130, &[]), (0, &[0, 63, 129])] {
+            bits.shift_into(shift, &mut shifted);
+            assert_eq!(set(&shifted), expected, "shifted by {}", shift);
+        }
+
+        // only the bits that weren't set yet are new
+        assert_eq!(shifted.or_with(&bits), vec![]);
+        assert_eq!(Bitset::new(130).or_with(&bits), vec![0, 63, 129]);
+    }
+
+    #[test]
+    fn agrees_with_every_subset() {
+        let reports = (Vecs { element: Integers(-30..60), lengths: 0..10 }, Integers(-50..100));
+
+        check(&reports, |(numbers, target)| {
+            let subset = min_subset_sum(numbers, *target).map_err(|e| e.to_string())?;
+
+            // the fewest entries any subset summing to the target has
+            let fewest = (0..=numbers.len()).find(|&r| {
+                combinations(numbers.len(), r, |indices| indices.iter().map(|&i| numbers[i]).sum::<i64>() == *target)
+            });
+
+            equal(subset.as_ref().map(|subset| subset.len()), fewest)?;
+
+            if let Some(subset) = subset {
+                for pair in subset.windows(2) {
+                    equal(pair[0].0 < pair[1].0, true)?;
+                }
+
+                for &(index, value) in &subset {
+                    equal(numbers[index], value)?;
+                }
+
+                equal(subset.iter().map(|&(_, value)| value).sum::<i64>(), *target)?;
+            }
+
+            Ok(())
+        });
+    }
+}
@@ -0,0 +1,266 @@
+// Finding k entries of an expense report which sum to a target, for any k and
+// target: two pointers over the sorted entries for up to three entries, and
+// meeting in the middle for more. Sums are taken in the wide type of the
+// entries, so they can't overflow however large the entries are.
+use std::collections::HashMap;
+
+use crate::number::{wide_sum, Number};
+
+// an entry's index in the report, and its value
+pub type Entry<T> = (usize, T);
+
+// returns k entries of numbers that sum to target, in the order they appear
+// in numbers, or None if no k entries do
+pub fn find_k_sum<T: Number>(numbers: &[T], k: usize, target: T::Wide) -> Option<Vec<Entry<T>>> {
+    // there aren't k entries to choose, which meeting in the middle would
+    // only find out after enumerating every half
+    if k > numbers.len() {
+        return None;
+    }
+
+    let mut indices = if k <= 3 {
+        two_pointers(numbers, k, target)?
+    } else {
+        meet_in_the_middle(numbers, k, target)?
+    };
+
+    indices.sort_unstable();
+
+    Some(indices.into_iter().map(|index| (index, numbers[index].clone())).collect())
+}
+
+// pins each entry in turn, smallest first, and searches the larger entries for
+// the rest, down to the last two which are found with two pointers. This takes
+// O(n^(k - 1)), so it's only quick for small k.
+pub fn two_pointers<T: Number>(numbers: &[T], k: usize, target: T::Wide) -> Option<Vec<usize>> {
+    let mut sorted = (0..numbers.len()).collect::<Vec<_>>();
+    sorted.sort_by(|&a, &b| numbers[a].cmp(&numbers[b]));
+
+    pinned_search(numbers, &sorted, k, target)
+}
+
+fn pinned_search<T: Number>(numbers: &[T], sorted: &[usize], k: usize, target: T::Wide) -> Option<Vec<usize>> {
+    let value = |position: usize| numbers[sorted[position]].widen();
+
+    match k {
+        0 => if target == T::Wide::from(0) { Some(vec![]) } else { None },
+        1 => {
+            let position = sorted.binary_search_by(|&index| numbers[index].widen().cmp(&target)).ok()?;
+            Some(vec![sorted[position]])
+        }
+        2 => {
+            if sorted.is_empty() {
+                return None;
+            }
+
+            let mut front = 0;
+            let mut back = sorted.len() - 1;
+
+            while front < back {
+                let sum = value(front) + value(back);
+
+                if sum == target {
+                    return Some(vec![sorted[front], sorted[back]]);
+                } else if sum < target {
+                    front += 1;
+                } else {
+                    back -= 1;
+                }
+            }
+
+            None
+        }
+        _ => (0..sorted.len()).find_map(|pinned| {
+            let rest = target.clone() - value(pinned);
+            let mut found = pinned_search(numbers, &sorted[(pinned + 1)..], k - 1, rest)?;
+            found.push(sorted[pinned]);
+            Some(found)
+        })
+    }
+}
+
+// splits the k entries into the first half and the second half by index, and
+// looks up the sum each second half needs from the first halves, which takes
+// O(n^(k / 2)) time and as much memory
+pub fn meet_in_the_middle<T: Number>(numbers: &[T], k: usize, target: T::Wide) -> Option<Vec<usize>> {
+    let first = k / 2;
+    let second = k - first;
+
+    let sum = |indices: &[usize]| wide_sum(indices.iter().map(|&index| &numbers[index]));
+
+    // of the first halves with each sum, the one ending the earliest, since it
+    // leaves the most entries after it for a second half
+    let mut first_halves: HashMap<T::Wide, Vec<usize>> = HashMap::new();
+
+    combinations(numbers.len(), first, |indices| {
+        let earliest = first_halves.entry(sum(indices)).or_insert_with(|| indices.to_vec());
+
+        if indices.last() < earliest.last() {
+            *earliest = indices.to_vec();
+        }
+
+        false
+    });
+
+    let mut found = None;
+
+    combinations(numbers.len(), second, |indices| {
+        if let Some(first_half) = first_halves.get(&(target.clone() - sum(indices))) {
+            if first_half.last().is_none() || first_half.last() < indices.first() {
+                found = Some(first_half.iter().chain(indices).copied().collect());
+            }
+        }
+
+        found.is_some()
+    });
+
+    found
+}
+
+// every distinct combination of k values of numbers which sum to target, where
+// combinations of the same values on different lines are the same, each as the
+// entries of the earliest lines holding its values
+pub fn all_k_sums<T: Number>(numbers: &[T], k: usize, target: T::Wide) -> Vec<Vec<Entry<T>>> {
+    let mut lines: HashMap<&T, Vec<usize>> = HashMap::new();
+
+    for (index, number) in numbers.iter().enumerate() {
+        lines.entry(number).or_default().push(index);
+    }
+
+    let mut all = vec![];
+
+    each_k_sum(numbers, k, target, &mut |values| {
+        // the values are in order, so repeated values are next to each other
+        let mut entries = values.iter().enumerate()
+            .map(|(position, value)| {
+                let repeats = values[..position].iter().filter(|&v| v == value).count();
+                (lines[value][repeats], value.clone())
+            })
+            .collect::<Vec<_>>();
+
+        entries.sort_unstable();
+        all.push(entries);
+    });
+
+    all.sort_unstable();
+    all
+}
+
+// the number of combinations all_k_sums would return, without keeping them
+pub fn count_k_sums<T: Number>(numbers: &[T], k: usize, target: T::Wide) -> u64 {
+    let mut count = 0;
+    each_k_sum(numbers, k, target, &mut |_| count += 1);
+
+    count
+}
+
+// calls f with the values of every distinct combination of k values of numbers
+// which sum to target, in increasing order. Like two_pointers it pins the
+// smallest values, here as many times as they are repeated, which takes
+// O(m^(k - 1)) for m distinct values.
+pub fn each_k_sum<T: Number>(numbers: &[T], k: usize, target: T::Wide, f: &mut dyn FnMut(&[T])) {
+    if k > numbers.len() {
+        return;
+    }
+
+    let mut sorted = numbers.to_vec();
+    sorted.sort_unstable();
+
+    // each distinct value, and the number of times it's repeated
+    let mut values: Vec<(T, usize)> = vec![];
+
+    for number in sorted {
+        match values.last_mut() {
+            Some((value, times)) if *value == number => *times += 1,
+            _ => values.push((number, 1))
+        }
+    }
+
+    pinned_sums(&values, k, target, &mut vec![], f);
+}
+
+fn pinned_sums<T: Number>(values: &[(T, usize)], k: usize, target: T::Wide, chosen: &mut Vec<T>, f: &mut dyn FnMut(&[T])) {
+    let mut found = |chosen: &mut Vec<T>, more: &[&T]| {
+        let len = chosen.len();
+        chosen.extend(more.iter().map(|&value| value.clone()));
+        f(chosen);
+        chosen.truncate(len);
+    };
+
+    match k {
+        0 => if target == T::Wide::from(0) { found(chosen, &[]) },
+        1 => {
+            if let Ok(position) = values.binary_search_by(|(value, _)| value.widen().cmp(&target)) {
+                found(chosen, &[&values[position].0]);
+            }
+        }
+        2 => {
+            // a value may pair with itself if it's repeated
+            let mut front = 0;
+            let mut back = values.len();
+
+            while front < back {
+                let (low, low_times) = &values[front];
+                let high = &values[back - 1].0;
+                let sum = low.widen() + high.widen();
+
+                if sum < target {
+                    front += 1;
+                } else if sum > target {
+                    back -= 1;
+                } else {
+                    if front < back - 1 || *low_times > 1 {
+                        found(chosen, &[low, high]);
+                    }
+
+                    front += 1;
+                    back -= 1;
+                }
+            }
+        }
+        _ => {
+            for (pinned, (value, times)) in values.iter().enumerate() {
+                let mut rest = target.clone();
+
+                for repeats in 1..=(*times).min(k) {
+                    rest = rest - value.widen();
+                    chosen.push(value.clone());
+
+                    pinned_sums(&values[(pinned + 1)..], k - repeats, rest.clone(), chosen, f);
+                }
+
+                chosen.truncate(chosen.len() - (*times).min(k));
+            }
+        }
+    }
+}
+
+// calls f with every combination of r of the indices 0..n, in lexicographic
+// order, until it returns true; returns whether it did
+pub fn combinations<F>(n: usize, r: usize, mut f: F) -> bool
+where F: FnMut(&[usize]) -> bool {
+    if r > n {
+        return false;
+    }
+
+    let mut indices = (0..r).collect::<Vec<_>>();
+
+    loop {
+        if f(&indices) {
+            return true;
+        }
+
+        // the last index which can still move right, after which the others
+        // follow on from it
+        let movable = match (0..r).rev().find(|&i| indices[i] < n - r + i) {
+            Some(i) => i,
+            None => return false
+        };
+
+        indices[movable] += 1;
+
+        for i in (movable + 1)..r {
+            indices[i] = indices[i - 1] + 1;
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::grapheme::graphemes;
+use crate::parse::{numbered_lines, parse_captures, parse_lines, LineError, ParseError};
+
+pub mod part1;
+pub mod part2;
+pub mod policy;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+
+// a line of the database: the two numbers of the policy are interpreted
+// differently by each part, and by each of the policies. The letter is a
+// single grapheme cluster, which may be more than one char.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Entry {
+    pub first: usize,
+    pub second: usize,
+    pub letter: String,
+    pub password: String
+}
+
+// as it was written in the database
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{} {}: {}", self.first, self.second, self.letter, self.password)
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Entry>, ParseError> {
+    parse_lines(input, parse_entry)
+}
+
+// the entries with the line each is on, for reporting them
+pub fn parse_numbered(input: &str) -> Result<Vec<(usize, Entry)>, ParseError> {
+    numbered_lines(input)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| parse_entry(line).map(|entry| (number, entry)).map_err(|e| e.at_line(number, line)))
+        .collect()
+}
+
+pub fn parse_entry(line: &str) -> Result<Entry, LineError> {
+    lazy_static! {
+        static ref LINE_PARSER: Regex = Regex::new(r"^(\d+)-(\d+) (.+?): (.*)$").unwrap();
+    }
+
+    let (first, second, Letter(letter), password) =
+        parse_captures(&LINE_PARSER, line, "`<first>-<second> <letter>: <password>`")?;
+
+    Ok(Entry { first, second, letter, password })
+}
+
+struct Letter(String);
+
+impl FromStr for Letter {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let mut graphemes = graphemes(s);
+
+        match (graphemes.next(), graphemes.next()) {
+            (Some(_), None) => Ok(Letter(s.to_owned())),
+            _ => Err(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use policy::{PasswordPolicy, Units};
+
+    use crate::property::{check, equal, Integers, Vecs};
+
+    const EXAMPLE: &str = "\
+1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+";
+
+    #[test]
+    fn parse_example() {
+        assert_eq!(
+            parse(EXAMPLE).unwrap()[0],
+            Entry { first: 1, second: 3, letter: "a".to_owned(), password: "abcde".to_owned() }
+        );
+    }
+
+    #[test]
+    fn part1_example() {
+        let validity = parse(EXAMPLE).unwrap().iter().map(part1::is_valid).collect::<Vec<_>>();
+
+        assert_eq!(validity, vec![true, false, true]);
+        assert_eq!(part1(EXAMPLE), Ok(2));
+    }
+
+    #[test]
+    fn part2_example() {
+        let validity = parse(EXAMPLE).unwrap().iter().map(part2::is_valid).collect::<Vec<_>>();
+
+        assert_eq!(validity, vec![true, false, false]);
+        assert_eq!(part2(EXAMPLE), Ok(1));
+    }
+
+    #[test]
+    fn policies() {
+        let entries = parse("1-3 a: abcde\n2-5 b: bcbdb\n4-9 c: ccccccccc\n3-4 x: xyz\n").unwrap();
+
+        let valid = |name: &str| {
+            let policy = policy::policy(name).unwrap();
+            entries.iter().map(|entry| policy.is_valid(entry, Units::Chars)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(valid("sled"), vec![true, true, true, false]);
+        assert_eq!(valid("toboggan"), vec![true, true, false, false]);
+        assert_eq!(valid("exactly-one"), vec![true, false, false, false]);
+        assert_eq!(valid("distinct"), vec![true, true, false, true]);
+        assert_eq!(valid("forbidden"), vec![false, false, false, false]);
+
+        assert_eq!(policy::count_valid(&entries, &policy::Forbidden, Units::Chars), 0);
+        assert!(policy::policy("lenient").is_none());
+    }
+
+    #[test]
+    fn violations() {
+        let entries = parse_numbered("1-3 a: abcde\n\n1-3 b: cdefg\n2-9 c: ccccccccc\n").unwrap();
+        assert_eq!(entries[1].0, 3);
+        assert_eq!(entries[1].1.to_string(), "1-3 b: cdefg");
+
+        let reasons = |violations: Vec<policy::Violation>| {
+            violations.into_iter().map(|v| (v.line, v.policy, v.reason)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(reasons(policy::violations(&entries, &policy::SledRental, Units::Chars)), vec![
+            (3, "sled", "contains 0 of 'b', requires 1-3".to_owned())
+        ]);
+        assert_eq!(reasons(policy::violations(&entries, &policy::Toboggan, Units::Chars)), vec![
+            (3, "toboggan", "neither position 1 nor 3 matches 'b'".to_owned()),
+            (4, "toboggan", "positions 2 and 9 both match 'c'".to_owned())
+        ]);
+
+        // only the last entry is valid under one and not the other
+        assert_eq!(reasons(policy::disagreements(&entries, &policy::SledRental, &policy::Toboggan, Units::Chars)), vec![
+            (4, "toboggan", "positions 2 and 9 both match 'c'".to_owned())
+        ]);
+        assert_eq!(reasons(policy::disagreements(&entries, &policy::Forbidden, &policy::Toboggan, Units::Chars)), vec![
+            (1, "forbidden", "contains 1 of 'a', which is forbidden".to_owned()),
+            (3, "toboggan", "neither position 1 nor 3 matches 'b'".to_owned())
+        ]);
+
+        let entry = parse_entry("3-4 x: xyz").unwrap();
+        assert_eq!(policy::ExactlyOne.check(&entry, Units::Chars), Err("positions 3 to 4 contain 0 of 'x', requires exactly 1".to_owned()));
+        assert_eq!(policy::AtLeastDistinct.check(&parse_entry("4-9 c: cccc").unwrap(), Units::Chars), Err("contains 1 different characters, requires at least 4".to_owned()));
+    }
+
+    #[test]
+    fn same_units_for_ascii() {
+        // printable ASCII, as a line of the database can't hold a line break
+        let ascii = Vecs { element: Integers(0x20..0x7f), lengths: 0..12 };
+        let strategy = ((Integers(0..12), Integers(0..12)), (Integers(0x21..0x7f), ascii));
+
+        check(&strategy, |((first, second), (letter, password))| {
+            let entry = Entry {
+                first: *first as usize,
+                second: *second as usize,
+                letter: char::from(*letter as u8).to_string(),
+                password: password.iter().map(|&c| char::from(c as u8)).collect()
+            };
+
+            for policy in policy::POLICIES {
+                equal(policy.check(&entry, Units::Chars), policy.check(&entry, Units::Graphemes))?;
+            }
+
+            Ok(())
+        });
+
+        let entries = parse(INPUT).unwrap();
+
+        for policy in policy::POLICIES {
+            assert_eq!(policy::count_valid(&entries, *policy, Units::Chars), policy::count_valid(&entries, *policy, Units::Graphemes));
+        }
+    }
+
+    #[test]
+    fn graphemes() {
+        // an e with a combining acute accent, which looks the same as é
+        let entry = parse_entry("1-2 e\u{301}: cafe\u{301} re\u{301}sume\u{301}").unwrap();
+        assert_eq!(entry.letter, "e\u{301}");
+
+        assert_eq!(policy::SledRental.check(&entry, Units::Graphemes), Err("contains 3 of 'e\u{301}', requires 1-2".to_owned()));
+
+        // which as chars could never match, so it's counted in graphemes anyway
+        assert_eq!(policy::SledRental.check(&entry, Units::Chars), Err("contains 3 of 'e\u{301}', requires 1-2".to_owned()));
+        assert_eq!(Units::Chars.split_password(&entry), Units::Graphemes.split(&entry.password));
+
+        // the accent moves every later position on by one char
+        let entry = parse_entry("5-6 s: cafe\u{301}ss").unwrap();
+        assert!(policy::Toboggan.is_valid(&entry, Units::Chars));
+        assert_eq!(policy::Toboggan.check(&entry, Units::Graphemes), Err("positions 5 and 6 both match 's'".to_owned()));
+        assert!(policy::ExactlyOne.is_valid(&parse_entry("5-5 s: cafe\u{301}s").unwrap(), Units::Graphemes));
+        assert!(!policy::ExactlyOne.is_valid(&parse_entry("5-5 s: cafe\u{301}s").unwrap(), Units::Chars));
+
+        // a flag is one character, of two regional indicators
+        let entry = parse_entry("3-3 x: \u{1f1eb}\u{1f1f7}\u{1f1e9}\u{1f1ea}").unwrap();
+        assert!(policy::AtLeastDistinct.is_valid(&entry, Units::Chars));
+        assert!(!policy::AtLeastDistinct.is_valid(&entry, Units::Graphemes));
+
+        // a letter can't be more than one grapheme cluster
+        let error = parse("1-3 ab: abcde\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.len), (1, 5, 2));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = parse("1-3 a: abcde\n1 b: cdefg\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected `<first>-<second> <letter>: <password>`, found `1 b: cdefg`");
+
+        let error = parse("1-99999999999999999999 a: abcde\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.len), (1, 3, 20));
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(445));
+        assert_eq!(part2(INPUT), Ok(491));
+    }
+}
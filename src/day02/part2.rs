@@ -0,0 +1,39 @@
+/* --- Part Two ---
+
+While it appears you validated the passwords correctly, they don't seem to be what the Official Toboggan Corporate Authentication System is expecting.
+
+The shopkeeper suddenly realizes that he just accidentally explained the password policy rules from his old job at the sled rental place down the street! The Official Toboggan Corporate Policy actually works a little differently.
+
+Each policy actually describes two positions in the password, where 1 means the first character, 2 means the second character, and so on. (Be careful; Toboggan Corporate Policies have no concept of "index zero"!) Exactly one of these positions must contain the given letter. Other occurrences of the letter are irrelevant for the purposes of policy enforcement.
+
+Given the same example list from above:
+
+    1-3 a: abcde is valid: position 1 contains a and position 3 does not.
+    1-3 b: cdefg is invalid: neither position 1 nor position 3 contains b.
+    2-9 c: ccccccccc is invalid: both position 2 and position 9 contain c.
+
+How many passwords are valid according to the new interpretation of the policies?
+*/
+use crate::parse::ParseError;
+use crate::registry::Details;
+
+use super::policy::{count_valid, PasswordPolicy, Toboggan, Units};
+use super::{parse, Entry};
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn solve(entries: &[Entry]) -> usize {
+    count_valid(entries, &Toboggan, Units::Chars)
+}
+
+pub fn details(entries: &[Entry]) -> Details {
+    vec![("entries", entries.len().to_string())]
+}
+
+// the first and second numbers are 1-indexed positions, exactly one of
+// which must contain the letter
+pub fn is_valid(entry: &Entry) -> bool {
+    Toboggan.is_valid(entry, Units::Chars)
+}
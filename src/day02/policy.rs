@@ -0,0 +1,230 @@
+// The policies a password can be checked against. Every line of the database
+// has the same shape, `<first>-<second> <letter>: <password>`, and each policy
+// decides what the two numbers and the letter mean: part 1 is the sled rental
+// policy and part 2 the Toboggan one. The positions and counts are of chars,
+// as in the puzzle, or of grapheme clusters, so that a letter and its accents
+// count as one character however they're encoded.
+use crate::grapheme::graphemes;
+
+use super::Entry;
+
+// what a policy counts as a character of a password. A letter of more than
+// one char, such as an e and a combining accent, could never match a single
+// char, so entries with one are always counted in grapheme clusters
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Units {
+    Chars,
+    Graphemes
+}
+
+impl Units {
+    pub fn split(self, s: &str) -> Vec<&str> {
+        match self {
+            Units::Chars => s.char_indices().map(|(index, c)| &s[index..(index + c.len_utf8())]).collect(),
+            Units::Graphemes => graphemes(s).collect()
+        }
+    }
+
+    // the characters of the password of entry, in units its letter is one of
+    pub fn split_password(self, entry: &Entry) -> Vec<&str> {
+        match self {
+            Units::Chars if entry.letter.chars().nth(1).is_some() => Units::Graphemes.split(&entry.password),
+            units => units.split(&entry.password)
+        }
+    }
+}
+
+pub trait PasswordPolicy: Sync {
+    // what it's selected by on the command line
+    fn name(&self) -> &'static str;
+
+    // how it reads the numbers and the letter, for listing the policies
+    fn description(&self) -> &'static str;
+
+    // why the password of entry is invalid, if it is
+    fn check(&self, entry: &Entry, units: Units) -> Result<(), String>;
+
+    fn is_valid(&self, entry: &Entry, units: Units) -> bool {
+        self.check(entry, units).is_ok()
+    }
+}
+
+pub const POLICIES: &[&dyn PasswordPolicy] = &[&SledRental, &Toboggan, &ExactlyOne, &AtLeastDistinct, &Forbidden];
+
+pub fn policy(name: &str) -> Option<&'static dyn PasswordPolicy> {
+    POLICIES.iter().copied().find(|policy| policy.name() == name)
+}
+
+// the number of entries whose password is valid under policy
+pub fn count_valid(entries: &[Entry], policy: &dyn PasswordPolicy, units: Units) -> usize {
+    entries.iter().filter(|entry| policy.is_valid(entry, units)).count()
+}
+
+// an entry of the database which is invalid under a policy, and why
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Violation {
+    pub line: usize,
+    pub entry: Entry,
+    pub policy: &'static str,
+    pub reason: String
+}
+
+// the entries which are invalid under policy, with their line numbers
+pub fn violations(entries: &[(usize, Entry)], policy: &dyn PasswordPolicy, units: Units) -> Vec<Violation> {
+    entries.iter()
+        .filter_map(|(line, entry)| violation(*line, entry, policy, units))
+        .collect()
+}
+
+// the entries which are valid under one of the policies but not the other,
+// each as its violation of the other, for seeing what moving from one policy
+// to the other would change
+pub fn disagreements(entries: &[(usize, Entry)], a: &dyn PasswordPolicy, b: &dyn PasswordPolicy, units: Units) -> Vec<Violation> {
+    entries.iter()
+        .filter_map(|(line, entry)| {
+            match (violation(*line, entry, a, units), violation(*line, entry, b, units)) {
+                (Some(violation), None) | (None, Some(violation)) => Some(violation),
+                _ => None
+            }
+        })
+        .collect()
+}
+
+fn violation(line: usize, entry: &Entry, policy: &dyn PasswordPolicy, units: Units) -> Option<Violation> {
+    policy.check(entry, units).err().map(|reason| Violation {
+        line,
+        entry: entry.clone(),
+        policy: policy.name(),
+        reason
+    })
+}
+
+// the number of times the letter occurs in the password
+fn occurrences(entry: &Entry, units: Units) -> usize {
+    units.split_password(entry).into_iter().filter(|&unit| unit == entry.letter).count()
+}
+
+// whether the letter is at a 1-indexed position of the password
+fn is_at(password: &[&str], letter: &str, position: usize) -> bool {
+    position.checked_sub(1)
+        .and_then(|position| password.get(position))
+        .is_some_and(|&unit| unit == letter)
+}
+
+pub struct SledRental;
+
+impl PasswordPolicy for SledRental {
+    fn name(&self) -> &'static str {
+        "sled"
+    }
+
+    fn description(&self) -> &'static str {
+        "the letter occurs at least <first> and at most <second> times"
+    }
+
+    fn check(&self, entry: &Entry, units: Units) -> Result<(), String> {
+        let count = occurrences(entry, units);
+
+        if count >= entry.first && count <= entry.second {
+            Ok(())
+        } else {
+            Err(format!("contains {} of '{}', requires {}-{}", count, entry.letter, entry.first, entry.second))
+        }
+    }
+}
+
+pub struct Toboggan;
+
+impl PasswordPolicy for Toboggan {
+    fn name(&self) -> &'static str {
+        "toboggan"
+    }
+
+    fn description(&self) -> &'static str {
+        "the letter is at exactly one of positions <first> and <second>, from 1"
+    }
+
+    fn check(&self, entry: &Entry, units: Units) -> Result<(), String> {
+        let password = units.split_password(entry);
+        let is_at = |position| is_at(&password, &entry.letter, position);
+
+        match (is_at(entry.first), is_at(entry.second)) {
+            (true, true) => Err(format!("positions {} and {} both match '{}'", entry.first, entry.second, entry.letter)),
+            (false, false) => Err(format!("neither position {} nor {} matches '{}'", entry.first, entry.second, entry.letter)),
+            _ => Ok(())
+        }
+    }
+}
+
+pub struct ExactlyOne;
+
+impl PasswordPolicy for ExactlyOne {
+    fn name(&self) -> &'static str {
+        "exactly-one"
+    }
+
+    fn description(&self) -> &'static str {
+        "the letter is at exactly one position from <first> to <second>, from 1"
+    }
+
+    fn check(&self, entry: &Entry, units: Units) -> Result<(), String> {
+        let positions = entry.first..=entry.second;
+
+        let count = units.split_password(entry).into_iter()
+            .enumerate()
+            .filter(|&(index, unit)| unit == entry.letter && positions.contains(&(index + 1)))
+            .count();
+
+        if count == 1 {
+            Ok(())
+        } else {
+            Err(format!(
+                "positions {} to {} contain {} of '{}', requires exactly 1",
+                entry.first, entry.second, count, entry.letter
+            ))
+        }
+    }
+}
+
+pub struct AtLeastDistinct;
+
+impl PasswordPolicy for AtLeastDistinct {
+    fn name(&self) -> &'static str {
+        "distinct"
+    }
+
+    fn description(&self) -> &'static str {
+        "at least <first> different characters, whatever the letter and <second>"
+    }
+
+    fn check(&self, entry: &Entry, units: Units) -> Result<(), String> {
+        let mut characters = units.split_password(entry);
+        characters.sort_unstable();
+        characters.dedup();
+
+        if characters.len() >= entry.first {
+            Ok(())
+        } else {
+            Err(format!("contains {} different characters, requires at least {}", characters.len(), entry.first))
+        }
+    }
+}
+
+pub struct Forbidden;
+
+impl PasswordPolicy for Forbidden {
+    fn name(&self) -> &'static str {
+        "forbidden"
+    }
+
+    fn description(&self) -> &'static str {
+        "the letter doesn't occur at all, whatever the numbers"
+    }
+
+    fn check(&self, entry: &Entry, units: Units) -> Result<(), String> {
+        match occurrences(entry, units) {
+            0 => Ok(()),
+            count => Err(format!("contains {} of '{}', which is forbidden", count, entry.letter))
+        }
+    }
+}
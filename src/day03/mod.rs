@@ -0,0 +1,77 @@
+use crate::grid::Grid;
+use crate::parse::ParseError;
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+
+pub fn parse(input: &str) -> Result<Map, ParseError> {
+    let squares = Grid::parse_with(input, "`.` or `#`", |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None
+    })?;
+
+    Ok(Map { squares })
+}
+
+pub struct Map {
+    // true for tree, false for empty space
+    squares: Grid<bool>
+}
+
+impl Map {
+    pub fn height(&self) -> usize {
+        self.squares.height()
+    }
+
+    // the pattern repeats to the right as far as needed
+    pub fn is_tree(&self, row: usize, col: usize) -> bool {
+        *self.squares.get_wrapping(row, col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EXAMPLE), Ok(7));
+    }
+
+    #[test]
+    fn part2_example() {
+        let map = parse(EXAMPLE).unwrap();
+        let trees = part2::SLOPES.iter()
+            .map(|slope| part2::number_of_trees_encountered(&map, slope))
+            .collect::<Vec<_>>();
+
+        assert_eq!(trees, vec![2, 7, 3, 4, 2]);
+        assert_eq!(part2(EXAMPLE), Ok(336));
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(247));
+        assert_eq!(part2(INPUT), Ok(2983070376));
+    }
+}
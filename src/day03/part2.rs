@@ -0,0 +1,69 @@
+/* --- Part Two ---
+
+Time to check the rest of the slopes - you need to minimize the probability of a sudden arboreal stop, after all.
+
+Determine the number of trees you would encounter if, for each of the following slopes, you start at the top-left corner and traverse the map all the way to the bottom:
+
+    Right 1, down 1.
+    Right 3, down 1. (This is the slope you already checked.)
+    Right 5, down 1.
+    Right 7, down 1.
+    Right 1, down 2.
+
+In the above example, these slopes would find 2, 7, 3, 4, and 2 tree(s) respectively; multiplied together, these produce the answer 336.
+
+What do you get if you multiply together the number of trees encountered on each of the listed slopes?
+*/
+use crate::parse::ParseError;
+use crate::registry::Details;
+
+use super::{parse, Map};
+
+// (right, down)
+pub const SLOPES: &[(usize, usize)] = &[
+    (1, 1),
+    (3, 1),
+    (5, 1),
+    (7, 1),
+    (1, 2)
+];
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn solve(map: &Map) -> usize {
+    SLOPES.iter()
+        .map(|slope| number_of_trees_encountered(map, slope))
+        .product::<usize>()
+}
+
+pub fn details(map: &Map) -> Details {
+    let trees = SLOPES.iter()
+        .map(|slope| number_of_trees_encountered(map, slope).to_string())
+        .collect::<Vec<_>>();
+
+    // in the same order as SLOPES
+    vec![("trees", trees.join(", "))]
+}
+
+pub fn number_of_trees_encountered(map: &Map, slope: &(usize, usize)) -> usize {
+    let (slope_right, slope_down) = slope;
+    let (mut row, mut col) = (0, 0);
+    let mut trees = 0;
+
+    loop {
+        row += slope_down;
+        col += slope_right;
+
+        if row >= map.height() {
+            break
+        }
+
+        if map.is_tree(row, col) {
+            trees += 1;
+        }
+    }
+
+    trees
+}
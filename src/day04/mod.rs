@@ -0,0 +1,118 @@
+use crate::parse::{parse_fields, parse_records, ParseError};
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+
+// the (key, value) fields of a passport
+pub type Passport<'a> = Vec<(&'a str, &'a str)>;
+
+// passports separated by an empty line, each made of `key:value` fields
+pub fn parse(input: &str) -> Result<Vec<Passport<'_>>, ParseError> {
+    parse_records(input, |passport| {
+        let lines = passport.parse_lines(|line| {
+            parse_fields(line, |key| key.chars().count() == 3, "a `key:value` field with a three letter key")
+        })?;
+
+        Ok(lines.into_iter().flatten().collect())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
+byr:1937 iyr:2017 cid:147 hgt:183cm
+
+iyr:2013 ecl:amb cid:350 eyr:2023 pid:028048884
+hcl:#cfa07d byr:1929
+
+hcl:#ae17e1 iyr:2013
+eyr:2024
+ecl:brn pid:760753108 byr:1931
+hgt:179cm
+
+hcl:#cfa07d eyr:2025 pid:166559648
+iyr:2011 ecl:brn hgt:59in
+";
+
+    const INVALID_PASSPORTS: &str = "\
+eyr:1972 cid:100
+hcl:#18171d ecl:amb hgt:170 pid:186cm iyr:2018 byr:1926
+
+iyr:2019
+hcl:#602927 eyr:1967 hgt:170cm
+ecl:grn pid:012533040 byr:1946
+
+hcl:dab227 iyr:2012
+ecl:brn hgt:182cm pid:021572410 eyr:2020 byr:1992 cid:277
+
+hgt:59cm ecl:zzz
+eyr:2038 hcl:74454a iyr:2023
+pid:3556412378 byr:2007
+";
+
+    const VALID_PASSPORTS: &str = "\
+pid:087499704 hgt:74in ecl:grn iyr:2012 eyr:2030 byr:1980
+hcl:#623a2f
+
+eyr:2029 ecl:blu cid:129 byr:1989
+iyr:2014 pid:896056539 hcl:#a97842 hgt:165cm
+
+hcl:#888785
+hgt:164cm byr:2001 iyr:2015 cid:88
+pid:545766238 ecl:hzl
+eyr:2022
+
+iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719
+";
+
+    #[test]
+    fn part1_example() {
+        let validity = parse(EXAMPLE).unwrap().into_iter()
+            .map(|passport| part1::is_valid_passport(&passport))
+            .collect::<Vec<_>>();
+
+        assert_eq!(validity, vec![true, false, true, false]);
+        assert_eq!(part1(EXAMPLE), Ok(2));
+    }
+
+    #[test]
+    fn part2_field_examples() {
+        assert!(part2::is_year_between("2002", 1920, 2002));
+        assert!(!part2::is_year_between("2003", 1920, 2002));
+
+        assert!(part2::is_valid_height("60in"));
+        assert!(part2::is_valid_height("190cm"));
+        assert!(!part2::is_valid_height("190in"));
+        assert!(!part2::is_valid_height("190"));
+
+        assert!(part2::is_valid_hair_color("#123abc"));
+        assert!(!part2::is_valid_hair_color("#123abz"));
+        assert!(!part2::is_valid_hair_color("123abc"));
+
+        assert!(part2::is_valid_eye_color("brn"));
+        assert!(!part2::is_valid_eye_color("wat"));
+
+        assert!(part2::is_valid_pid("000000001"));
+        assert!(!part2::is_valid_pid("0123456789"));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(INVALID_PASSPORTS), Ok(0));
+        assert_eq!(part2(VALID_PASSPORTS), Ok(4));
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(230));
+        assert_eq!(part2(INPUT), Ok(156));
+    }
+}
@@ -0,0 +1,91 @@
+use crate::parse::{parse_lines, LineError, ParseError};
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+
+// returns the (row, column) of each boarding pass
+pub fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    parse_lines(input, |line| get_seat_info(line.trim_end()))
+}
+
+// returns row, column
+pub fn get_seat_info(desc: &str) -> Result<(usize, usize), LineError> {
+    if desc.len() != 10 || !desc.is_ascii() {
+        return Err(LineError::whole(desc, "10 characters, `F` or `B` then `L` or `R`"));
+    }
+
+    Ok((
+        parse_binary(&desc[..7], 'B', 'F')?,
+        parse_binary(&desc[7..], 'R', 'L').map_err(|e| e.offset(7))?
+    ))
+}
+
+pub fn parse_binary(s: &str, one: char, zero: char) -> Result<usize, LineError> {
+    let mut result = 0;
+
+    for (index, c) in s.char_indices() {
+        result <<= 1;
+        if c == one {
+            result += 1;
+        } else if c != zero {
+            return Err(LineError::new(index, c.len_utf8(), &format!("`{}` or `{}`", zero, one)));
+        }
+    }
+
+    Ok(result)
+}
+
+pub fn seat_id(row: usize, col: usize) -> usize {
+    row * 8 + col
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seat_examples() {
+        assert_eq!(get_seat_info("FBFBBFFRLR"), Ok((44, 5)));
+        assert_eq!(seat_id(44, 5), 357);
+
+        assert_eq!(get_seat_info("BFFFBBFRRR"), Ok((70, 7)));
+        assert_eq!(get_seat_info("FFFBBBFRRR"), Ok((14, 7)));
+        assert_eq!(get_seat_info("BBFFBBFRLL"), Ok((102, 4)));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1("FBFBBFFRLR\nBFFFBBFRRR\nFFFBBBFRRR\nBBFFBBFRLL\n"), Ok(Some(820)));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = parse("FBFBBFFRLR\nFBFBBFFRXR\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 9: expected `L` or `R`, found `X`");
+
+        let error = parse("FBFBBFFRL\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.len), (1, 1, 9));
+    }
+
+    #[test]
+    fn part2_missing_seat() {
+        // every seat from row 1 to row 3, except row 2 column 3
+        let seats = (8..32)
+            .filter(|&id| id != 19)
+            .map(|id| (id / 8, id % 8))
+            .collect::<Vec<_>>();
+
+        assert_eq!(part2::solve(&seats), Some(19));
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(Some(842)));
+        assert_eq!(part2(INPUT), Ok(Some(617)));
+    }
+}
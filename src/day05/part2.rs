@@ -0,0 +1,51 @@
+/* --- Part Two ---
+
+Ding! The "fasten seat belt" signs have turned on. Time to find your seat.
+
+It's a completely full flight, so your seat should be the only missing boarding pass in your list. However, there's a catch: some of the seats at the very front and back of the plane don't exist on this aircraft, so they'll be missing from your list as well.
+
+Your seat wasn't at the very front or back, though; the seats with IDs +1 and -1 from yours will be in your list.
+
+What is the ID of your seat?
+*/
+use std::collections::HashSet;
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::registry::Details;
+
+use super::{parse, seat_id};
+
+pub fn part2(input: &str) -> Result<Option<usize>, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+// the only missing seat whose neighbours (by id) are both taken
+pub fn solve(seats: &[(usize, usize)]) -> Option<usize> {
+    let taken = seats.iter()
+        .map(|&(row, col)| seat_id(row, col))
+        .collect::<HashSet<_>>();
+
+    (1..seat_id(128, 0))
+        .find(|id| !taken.contains(id) && taken.contains(&(id - 1)) && taken.contains(&(id + 1)))
+}
+
+pub fn details(seats: &[(usize, usize)]) -> Details {
+    match solve(seats) {
+        Some(id) => vec![("row", (id / 8).to_string()), ("column", (id % 8).to_string())],
+        None => vec![]
+    }
+}
+
+// one line per row, with an X for each taken seat
+pub fn seat_chart(seats: &[(usize, usize)]) -> Vec<String> {
+    let mut chart = Grid::new(8, 128, ' ');
+
+    for &(row, col) in seats {
+        chart[(row, col)] = 'X';
+    }
+
+    chart.rows()
+        .map(|row| row.iter().collect::<String>())
+        .collect()
+}
@@ -0,0 +1,66 @@
+use crate::parse::{parse_records, LineError, ParseError};
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+
+// groups separated by an empty line, with one line of answered questions
+// (a to z) per member
+pub fn parse(input: &str) -> Result<Vec<Vec<&str>>, ParseError> {
+    parse_records(input, |group| group.parse_lines(|line| {
+        let line = line.trim_end();
+
+        check_answers(line).map(|_| line)
+    }))
+}
+
+fn check_answers(line: &str) -> Result<(), LineError> {
+    match line.char_indices().find(|(_, c)| !c.is_ascii_lowercase()) {
+        Some((index, c)) => Err(LineError::new(index, c.len_utf8(), "a question from `a` to `z`")),
+        None => Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1::questions_covered(["abcx", "abcy", "abcz"].iter()), 6);
+        assert_eq!(part1(EXAMPLE), Ok(11));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EXAMPLE), Ok(6));
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(6310));
+        assert_eq!(part2(INPUT), Ok(3193));
+    }
+}
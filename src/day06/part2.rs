@@ -0,0 +1,70 @@
+/* --- Part Two ---
+
+As you finish the last group's customs declaration, you notice that you misread one word in the instructions:
+
+You don't need to identify the questions to which anyone answered "yes"; you need to identify the questions to which everyone answered "yes"!
+
+Using the same example as above:
+
+abc
+
+a
+b
+c
+
+ab
+ac
+
+a
+a
+a
+a
+
+b
+
+This list represents answers from five groups:
+
+    In the first group, everyone (all 1 person) answered "yes" to 3 questions: a, b, and c.
+    In the second group, there is no question to which everyone answered "yes".
+    In the third group, everyone answered yes to only 1 question, a. Since some people did not answer "yes" to b or c, they don't count.
+    In the fourth group, everyone answered yes to only 1 question, a.
+    In the fifth group, everyone (all 1 person) answered "yes" to 1 question, b.
+
+In this example, the sum of these counts is 3 + 0 + 1 + 1 + 1 = 6.
+
+For each group, count the number of questions to which everyone answered "yes". What is the sum of those counts?
+*/
+use std::collections::HashSet;
+
+use crate::parse::ParseError;
+
+use super::parse;
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn solve(groups: &[Vec<&str>]) -> usize {
+    groups.iter()
+        .map(|group| questions_completely_covered(&mut group.iter()))
+        .sum::<usize>()
+}
+
+pub fn questions_completely_covered<I, S>(group_members: &mut I) -> usize
+where I: Iterator<Item = S>, S: AsRef<str> {
+    let first_member_questions = match group_members.next() {
+        Some(member) => member.as_ref().chars().collect::<HashSet<_>>(),
+        None => return 0
+    };
+
+    group_members.fold(first_member_questions, |questions, next| {
+        let member_questions = next.as_ref().chars().collect::<HashSet<_>>();
+
+        // I admit this map->collect reduction is not really efficient,
+        // but you don't optimize for performance unless it's a problem
+        questions
+            .intersection(&member_questions)
+            .copied()
+            .collect::<HashSet<_>>()
+    }).len()
+}
@@ -0,0 +1,146 @@
+use std::collections::{HashMap, HashSet};
+
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::parse::{parse_lines, LineError, ParseError};
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+pub const TARGET_COLOR: &str = "shiny gold";
+
+// the color of bag a rule is for, and how many bags of each color it contains
+#[derive(Debug)]
+pub struct Rule {
+    pub name: String,
+    pub children: HashMap<String, usize>
+}
+
+// the rules, which each part builds its own graph from
+pub fn parse_rules(input: &str) -> Result<Vec<Rule>, ParseError> {
+    let mut colors = HashSet::new();
+
+    parse_lines(input, |line| {
+        let rule = parse_rule(line)?;
+
+        if colors.insert(rule.name.clone()) {
+            Ok(rule)
+        } else {
+            Err(LineError::new(0, rule.name.len(), "a color without an earlier rule"))
+        }
+    })
+}
+
+pub fn parse_rule(line: &str) -> Result<Rule, LineError> {
+    lazy_static! {
+        static ref RULE_PARSER: Regex = Regex::new(r"^(.+) bags contain (.+)\.$").unwrap();
+    }
+
+    let captures = RULE_PARSER.captures(line)
+        .ok_or_else(|| LineError::whole(line, "`<color> bags contain <contents>.`"))?;
+
+    let contents = captures.get(2).unwrap();
+
+    let parent_color = captures[1].to_owned();
+    let child_colors = parse_child_colors(contents.as_str())
+        .map_err(|e| e.offset(contents.start()))?;
+
+    Ok(Rule { name: parent_color, children: child_colors })
+}
+
+fn parse_child_colors(desc: &str) -> Result<HashMap<String, usize>, LineError> {
+    lazy_static! {
+        static ref CHILD_COLOR_PARSER: Regex = Regex::new(r"^(\d+) (.+) bags?$").unwrap();
+    }
+
+    if desc == "no other bags" {
+        return Ok(HashMap::new())
+    }
+
+    let mut offset = 0;
+
+    desc.split(", ")
+        .map(|child| {
+            let start = offset;
+            offset += child.len() + 2;
+
+            let captures = CHILD_COLOR_PARSER.captures(child)
+                .ok_or_else(|| LineError::new(start, child.len(), "`<count> <color> bags`"))?;
+
+            let count = captures[1].parse()
+                .map_err(|_| LineError::new(start, captures[1].len(), "a smaller count"))?;
+
+            Ok((captures[2].to_owned(), count))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+";
+
+    const DEEP_EXAMPLE: &str = "\
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EXAMPLE), Ok(4));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EXAMPLE), Ok(32));
+        assert_eq!(part2(DEEP_EXAMPLE), Ok(126));
+    }
+
+    #[test]
+    fn cycles() {
+        let cycle = "\
+shiny gold bags contain 1 dark red bag.
+dark red bags contain 2 dark orange bags, 1 shiny gold bag.
+dark orange bags contain no other bags.
+";
+
+        let error = part2(cycle).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 45: expected a color which doesn't lead back to dark red bags, found `shiny gold`");
+
+        // a bag containing itself
+        let error = part2("shiny gold bags contain 2 shiny gold bags.\n").unwrap_err();
+        assert_eq!((error.line, error.column, error.found()), (1, 27, "shiny gold".to_owned()));
+
+        // a cycle the shiny gold bag doesn't lead to is no problem, and
+        // neither is one for part 1
+        assert_eq!(part2("shiny gold bags contain 3 dark red bags.\nfaded blue bags contain 1 faded blue bag.\n"), Ok(3));
+        assert_eq!(part1(cycle), Ok(2));
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(172));
+        assert_eq!(part2(INPUT), Ok(39645));
+    }
+}
@@ -0,0 +1,131 @@
+/* --- Day 7: Handy Haversacks ---
+
+You land at the regional airport in time for your next flight. In fact, it looks like you'll even have time to grab some food: all flights are currently delayed due to issues in luggage processing.
+
+Due to recent aviation regulations, many rules (your puzzle input) are being enforced about bags and their contents; bags must be color-coded and must contain specific quantities of other color-coded bags. Apparently, nobody responsible for these regulations considered how long they would take to enforce!
+
+For example, consider the following rules:
+
+light red bags contain 1 bright white bag, 2 muted yellow bags.
+dark orange bags contain 3 bright white bags, 4 muted yellow bags.
+bright white bags contain 1 shiny gold bag.
+muted yellow bags contain 2 shiny gold bags, 9 faded blue bags.
+shiny gold bags contain 1 dark olive bag, 2 vibrant plum bags.
+dark olive bags contain 3 faded blue bags, 4 dotted black bags.
+vibrant plum bags contain 5 faded blue bags, 6 dotted black bags.
+faded blue bags contain no other bags.
+dotted black bags contain no other bags.
+
+These rules specify the required contents for 9 bag types. In this example, every faded blue bag is empty, every vibrant plum bag contains 11 bags (5 faded blue and 6 dotted black), and so on.
+
+You have a shiny gold bag. If you wanted to carry it in at least one other bag, how many different bag colors would be valid for the outermost bag? (In other words: how many colors can, eventually, contain at least one shiny gold bag?)
+
+In the above rules, the following options would be available to you:
+
+    A bright white bag, which can hold your shiny gold bag directly.
+    A muted yellow bag, which can hold your shiny gold bag directly, plus some other bags.
+    A dark orange bag, which can hold bright white and muted yellow bags, either of which could then hold your shiny gold bag.
+    A light red bag, which can hold bright white and muted yellow bags, either of which could then hold your shiny gold bag.
+
+So, in this example, the number of bag colors that can eventually contain at least one shiny gold bag is 4.
+
+How many bag colors can eventually contain at least one shiny gold bag? (The list of rules is quite long; make sure you get all of it.)
+*/
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::parse::ParseError;
+
+use super::{parse_rules, Rule, TARGET_COLOR};
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn parse(input: &str) -> Result<DirectionalGraph, ParseError> {
+    Ok(DirectionalGraph::from_rules(parse_rules(input)?.into_iter()))
+}
+
+// number of colors that can eventually contain a shiny gold bag
+pub fn solve(graph: &DirectionalGraph) -> usize {
+    if !graph.vertices.contains_key(TARGET_COLOR) {
+        return 0;
+    }
+
+    explore_destinations(
+        TARGET_COLOR,
+        |x| graph.vertices[x].parents.iter().map(|s| s as &str)
+    ).len()
+}
+
+// breadth-first search of all destinations reachable from source
+pub fn explore_destinations<'a, F, T>(source: &'a str, get_targets: F) -> Vec<&'a str>
+where F: Fn(&str) -> T, T: Iterator<Item = &'a str>{
+    let mut queue = VecDeque::new();
+    let mut explored = HashSet::new();
+    let mut found = HashSet::new();
+
+    queue.push_back(source);
+
+    while let Some(next) = queue.pop_front() {
+        explored.insert(next);
+
+        for destination in get_targets(next) {
+            found.insert(destination);
+
+            if !explored.contains(&destination) {
+                queue.push_back(destination)
+            }
+        }
+    }
+
+    found.into_iter().collect()
+}
+
+#[derive(Debug)]
+pub struct Vertex {
+    pub name: String,
+    pub children: HashSet<String>,
+    pub parents: HashSet<String>
+}
+
+impl Vertex {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_owned(), children: HashSet::new(), parents: HashSet::new() }
+    }
+}
+
+#[derive(Debug)]
+pub struct DirectionalGraph {
+    pub vertices: HashMap<String, Vertex>
+}
+
+impl DirectionalGraph {
+    pub fn from_rules<I>(rules: I) -> Self
+    where I: Iterator<Item = Rule> {
+        let mut graph = Self { vertices: HashMap::new() };
+
+        for rule in rules {
+            graph.add_vertex(rule);
+        }
+
+        graph
+    }
+
+    fn add_vertex(&mut self, rule: Rule) {
+        let Rule {name, children} = rule;
+
+        // parse has already rejected a second rule for the same color
+        let vertex = self.get_or_create_vertex(&name);
+
+        vertex.children = children.keys().cloned().collect::<HashSet<_>>();
+
+        for child in children.keys() {
+            self.get_or_create_vertex(child).parents.insert(name.to_owned());
+        }
+    }
+
+    fn get_or_create_vertex(&mut self, name: &str) -> &mut Vertex {
+        self.vertices.entry(name.to_owned())
+            .or_insert_with(|| Vertex::new(name))
+    }
+}
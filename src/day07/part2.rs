@@ -0,0 +1,151 @@
+/* --- Part Two ---
+
+It's getting pretty expensive to fly these days - not because of ticket prices, but because of the ridiculous number of bags you need to buy!
+
+Consider again your shiny gold bag and the rules from the above example:
+
+    faded blue bags contain 0 other bags.
+    dotted black bags contain 0 other bags.
+    vibrant plum bags contain 11 other bags: 5 faded blue bags and 6 dotted black bags.
+    dark olive bags contain 7 other bags: 3 faded blue bags and 4 dotted black bags.
+
+So, a single shiny gold bag must contain 1 dark olive bag (and the 7 bags within it) plus 2 vibrant plum bags (and the 11 bags within each of those): 1 + 1*7 + 2 + 2*11 = 32 bags!
+
+Of course, the actual rules have a small chance of going several levels deeper than this example; be sure to count all of the bags, even if the nesting becomes topologically impractical!
+
+Here's another example:
+
+shiny gold bags contain 2 dark red bags.
+dark red bags contain 2 dark orange bags.
+dark orange bags contain 2 dark yellow bags.
+dark yellow bags contain 2 dark green bags.
+dark green bags contain 2 dark blue bags.
+dark blue bags contain 2 dark violet bags.
+dark violet bags contain no other bags.
+
+In this example, a single shiny gold bag must contain 126 other bags.
+
+How many individual bags are required inside your single shiny gold bag?
+*/
+use std::collections::{HashMap, HashSet};
+
+use crate::parse::{numbered_lines, LineError, ParseError};
+
+use super::{parse_rule, parse_rules, Rule, TARGET_COLOR};
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+// rejects rules under which a shiny gold bag would hold infinitely many bags
+pub fn parse(input: &str) -> Result<DirectionalGraph, ParseError> {
+    let graph = DirectionalGraph::from_rules(parse_rules(input)?.into_iter());
+
+    match graph.find_cycle(TARGET_COLOR) {
+        Some((color, child)) => Err(cycle_error(input, color, child)),
+        None => Ok(graph)
+    }
+}
+
+// points at child in the contents of the rule for color
+fn cycle_error(input: &str, color: &str, child: &str) -> ParseError {
+    let (number, line) = numbered_lines(input)
+        .find(|(_, line)| parse_rule(line).is_ok_and(|rule| rule.name == color))
+        .expect("a color on a cycle has a rule");
+
+    // the contents start after the color of the rule, which may be the child
+    let contents = color.len() + " bags contain ".len();
+    let column = line[contents..].find(child).map_or(0, |column| contents + column);
+
+    let expected = format!("a color which doesn't lead back to {} bags", color);
+    LineError::new(column, child.len(), &expected).at_line(number, line)
+}
+
+// number of bags required inside a shiny gold bag
+pub fn solve(graph: &DirectionalGraph) -> usize {
+    let mut dynamic_results = HashMap::new();
+
+    number_of_bags_contained(
+        TARGET_COLOR,
+        &|x| graph.vertices.get(x)
+            .into_iter()
+            .flat_map(|vertex| vertex.children.iter().map(|(s, c)| (s as &str, *c))),
+        &mut dynamic_results
+    )
+}
+
+pub fn number_of_bags_contained<'a, F, T>(
+    source: &'a str, get_children: &F, dynamic_results: &mut HashMap<String, usize>
+) -> usize
+where F: Fn(&str) -> T, T: Iterator<Item = (&'a str, usize)> {
+    if dynamic_results.contains_key(source) {
+        return dynamic_results[source]
+    }
+
+    let mut result = 0;
+
+    for (child, count) in get_children(source) {
+        result += count * (1 + number_of_bags_contained(child, get_children, dynamic_results));
+    }
+
+    dynamic_results.insert(source.to_owned(), result);
+
+    result
+}
+
+pub type Vertex = Rule;
+
+#[derive(Debug)]
+pub struct DirectionalGraph {
+    pub vertices: HashMap<String, Vertex>
+}
+
+impl DirectionalGraph {
+    // a rule reachable from source whose contents lead back to its own color,
+    // as its color and the color in its contents which does
+    pub fn find_cycle(&self, source: &str) -> Option<(&str, &str)> {
+        let source = self.vertices.get_key_value(source)?.0.as_str();
+
+        // the colors on the path from source, and those already explored
+        let mut path = HashSet::new();
+        let mut explored = HashSet::new();
+
+        // each color on the path, with the colors in its contents left to visit
+        let mut stack = vec![(source, self.children(source))];
+        path.insert(source);
+
+        while let Some((color, children)) = stack.last_mut() {
+            let color = *color;
+
+            match children.next() {
+                Some(child) if path.contains(child) => return Some((color, child)),
+                Some(child) => if !explored.contains(child) {
+                    path.insert(child);
+                    stack.push((child, self.children(child)));
+                },
+                None => {
+                    path.remove(color);
+                    explored.insert(color);
+                    stack.pop();
+                }
+            }
+        }
+
+        None
+    }
+
+    fn children<'a>(&'a self, color: &str) -> impl Iterator<Item = &'a str> {
+        self.vertices.get(color)
+            .into_iter()
+            .flat_map(|vertex| vertex.children.keys().map(String::as_str))
+    }
+
+    pub fn from_rules<I>(rules: I) -> Self
+    where I: Iterator<Item = Rule> {
+        let vertices = rules
+            .map(|rule| (rule.name.to_owned(), rule))
+            .collect();
+
+        Self { vertices }
+    }
+}
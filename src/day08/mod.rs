@@ -0,0 +1,167 @@
+use crate::parse::{parse_lines, parse_number, LineError, ParseError};
+
+pub mod part1;
+pub mod part2;
+pub mod part2_optimized;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, Instruction::parse)
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub value: i32
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Opcode {
+    Nop, Acc, Jmp,
+}
+
+impl Instruction {
+    pub fn parse(instruction: &str) -> Result<Self, LineError> {
+        let (name, argument) = instruction.split_once(' ')
+            .ok_or_else(|| LineError::whole(instruction, "`<instruction> <argument>`"))?;
+
+        let opcode = match name {
+            "nop" => Opcode::Nop,
+            "acc" => Opcode::Acc,
+            "jmp" => Opcode::Jmp,
+            _ => return Err(LineError::new(0, name.len(), "`nop`, `acc` or `jmp`"))
+        };
+
+        let value = parse_number::<i32>(argument, "a signed integer")
+            .map_err(|e| e.offset(name.len() + 1))?;
+
+        Ok(Instruction { opcode, value })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::property::{check, Integers, Vecs};
+
+    // programs made of (opcode, argument) pairs, where the argument of a jump
+    // or nop is where it goes, so that no jump leaves the program and the
+    // programs stay valid as they shrink
+    fn program(pairs: &[(i64, i64)]) -> Vec<Instruction> {
+        pairs.iter().enumerate().map(|(index, &(opcode, argument))| {
+            let target = (argument % (pairs.len() as i64 + 1)) as i32;
+
+            match opcode {
+                0 => Instruction { opcode: Opcode::Acc, value: argument as i32 - 5 },
+                1 => Instruction { opcode: Opcode::Nop, value: target - index as i32 },
+                _ => Instruction { opcode: Opcode::Jmp, value: target - index as i32 }
+            }
+        }).collect()
+    }
+
+    fn listing(program: &[Instruction]) -> String {
+        program.iter()
+            .map(|i| format!("{} {:+}\n", format!("{:?}", i.opcode).to_lowercase(), i.value))
+            .collect()
+    }
+
+    const EXAMPLE: &str = "\
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EXAMPLE), Ok(Some(5)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2::find_repair(&parse(EXAMPLE).unwrap()), Some((7, 8)));
+        assert_eq!(part2(EXAMPLE), Ok(Some(8)));
+        assert_eq!(part2_optimized::part2(EXAMPLE), Ok(Some(8)));
+    }
+
+    #[test]
+    fn repair_through_a_loop() {
+        // searching back from the end reaches the loop between 1 and 2 before
+        // the jump from 4, and mustn't go round it forever
+        let code = parse("jmp +4\njmp +1\njmp -1\njmp +2\njmp -2\n").unwrap();
+
+        assert_eq!(part2::find_repair(&code), Some((2, 0)));
+        assert_eq!(part2_optimized::solve(&code), Some(0));
+    }
+
+    #[test]
+    fn repairs_agree() {
+        let programs = Vecs { element: (Integers(0..3), Integers(0..24)), lengths: 1..16 };
+
+        check(&programs, |pairs| {
+            let program = program(pairs);
+
+            // the puzzle only makes sense for programs that loop
+            if part1::solve(&program).is_none() {
+                return Ok(());
+            }
+
+            // several instructions may repair the program, in which case
+            // either of their accumulators will do
+            let repairs = (0..program.len())
+                .filter_map(|index| {
+                    let mut repaired = program.clone();
+                    repaired[index].opcode = match repaired[index].opcode {
+                        Opcode::Nop => Opcode::Jmp,
+                        Opcode::Jmp => Opcode::Nop,
+                        Opcode::Acc => return None
+                    };
+
+                    part2::try_run_to_end(&repaired)
+                })
+                .collect::<Vec<_>>();
+
+            let brute_force = part2::solve(&program);
+            let optimized = part2_optimized::solve(&program);
+
+            let agree = match optimized {
+                Some(acc) => repairs.contains(&acc),
+                None => repairs.is_empty()
+            };
+
+            if brute_force == repairs.first().copied() && agree {
+                Ok(())
+            } else {
+                Err(format!("{}brute force found {:?}, optimized found {:?}", listing(&program), brute_force, optimized))
+            }
+        });
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = parse("nop +0\nacc +1\njpm -2\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: expected `nop`, `acc` or `jmp`, found `jpm`");
+
+        let error = parse("acc +1x\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 5: expected a signed integer, found `+1x`");
+
+        let error = parse("nop\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 1: expected `<instruction> <argument>`, found `nop`");
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(Some(1331)));
+        assert_eq!(part2(INPUT), Ok(Some(1121)));
+        assert_eq!(part2_optimized::part2(INPUT), Ok(Some(1121)));
+    }
+}
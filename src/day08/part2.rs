@@ -0,0 +1,112 @@
+/* --- Part Two ---
+
+After some careful analysis, you believe that exactly one instruction is corrupted.
+
+Somewhere in the program, either a jmp is supposed to be a nop, or a nop is supposed to be a jmp. (No acc instructions were harmed in the corruption of this boot code.)
+
+The program is supposed to terminate by attempting to execute an instruction immediately after the last instruction in the file. By changing exactly one jmp or nop, you can repair the boot code and make it terminate correctly.
+
+For example, consider the same program from above:
+
+nop +0
+acc +1
+jmp +4
+acc +3
+jmp -3
+acc -99
+acc +1
+jmp -4
+acc +6
+
+If you change the first instruction from nop +0 to jmp +0, it would create a single-instruction infinite loop, never leaving that instruction. If you change almost any of the jmp instructions, the program will still eventually find another jmp instruction and loop forever.
+
+However, if you change the second-to-last instruction (from jmp -4 to nop -4), the program terminates! The instructions are visited in this order:
+
+nop +0  | 1
+acc +1  | 2
+jmp +4  | 3
+acc +3  |
+jmp -3  |
+acc -99 |
+acc +1  | 4
+nop -4  | 5
+acc +6  | 6
+
+After the last instruction (acc +6), the program terminates by attempting to run the instruction below the last instruction in the file. With this change, after the program terminates, the accumulator contains the value 8 (acc +1, acc +1, acc +6).
+
+Fix the program so that it terminates normally by changing exactly one jmp (to nop) or nop (to jmp). What is the value of the accumulator after the program terminates?
+*/
+use crate::parse::ParseError;
+use crate::registry::Details;
+
+use super::{parse, Instruction, Opcode};
+
+pub fn part2(input: &str) -> Result<Option<i32>, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn solve(instructions: &[Instruction]) -> Option<i32> {
+    find_repair(instructions).map(|(_, acc)| acc)
+}
+
+pub fn details(instructions: &[Instruction]) -> Details {
+    match find_repair(instructions) {
+        Some((index, _)) => vec![("fixed_instruction", index.to_string())],
+        None => vec![]
+    }
+}
+
+// returns the index of the instruction to flip, and the accumulator after the
+// repaired program terminates
+pub fn find_repair(instructions: &[Instruction]) -> Option<(usize, i32)> {
+    let mut code = instructions.to_vec();
+
+    for i in 0..code.len() {
+        let mut replacement_instruction = match code[i].opcode {
+            Opcode::Nop => Instruction { opcode: Opcode::Jmp, ..code[i] },
+            Opcode::Jmp => Instruction { opcode: Opcode::Nop, ..code[i] },
+            Opcode::Acc => continue
+        };
+
+        std::mem::swap(&mut code[i], &mut replacement_instruction);
+
+        if let Some(result) = try_run_to_end(&code) {
+            return Some((i, result));
+        }
+
+        std::mem::swap(&mut code[i], &mut replacement_instruction);
+    }
+
+    None
+}
+
+// returns the accumulator if the program terminates, or None if it loops
+pub fn try_run_to_end(instructions: &[Instruction]) -> Option<i32> {
+    let mut instructions = instructions.iter().map(|i| (i, false)).collect::<Vec<_>>();
+
+    let mut pc = 0;
+    let mut acc = 0;
+
+    while let Some((instruction, executed)) = instructions.get_mut(pc as usize) {
+        if *executed {
+            return None;
+        }
+
+        *executed = true;
+
+        match instruction.opcode {
+            Opcode::Jmp => {
+                pc += instruction.value;
+                continue;
+            }
+
+            Opcode::Nop => {}
+
+            Opcode::Acc => acc += instruction.value
+        }
+
+        pc += 1;
+    }
+
+    Some(acc)
+}
@@ -0,0 +1,85 @@
+use crate::parse::{parse_integers, ParseError};
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+pub const PREAMBLE_SIZE: usize = 25;
+// the example in the statement is short enough to need a shorter one
+pub const EXAMPLE_PREAMBLE_SIZE: usize = 5;
+
+pub fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+    parse_integers(input, "an integer")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::pair_sums::PairSums;
+
+    const EXAMPLE: &str = "\
+35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+";
+
+    #[test]
+    fn preamble_examples() {
+        let mut preamble = PairSums::<i64>::new();
+        (1..=25).for_each(|n| preamble.insert(n));
+
+        assert!(preamble.has_pair(&26));
+        assert!(preamble.has_pair(&49));
+        assert!(!preamble.has_pair(&100));
+        assert!(!preamble.has_pair(&50));
+
+        preamble.remove(&20);
+        preamble.insert(45);
+
+        assert!(preamble.has_pair(&26));
+        assert!(!preamble.has_pair(&65));
+        assert!(preamble.has_pair(&64));
+        assert!(preamble.has_pair(&66));
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1::solve_example(&parse(EXAMPLE).unwrap()), Some(127));
+        assert_eq!(part1::find_noncompliant(vec![1, 2, 3, 5, 8], 2), None);
+        assert_eq!(part1::find_noncompliant(vec![1, 2, 3, 5, 7], 2), Some(7));
+    }
+
+    #[test]
+    fn part2_example() {
+        let numbers = parse(EXAMPLE).unwrap();
+
+        assert_eq!(part2::find_contiguous_set(&numbers, 127), Some(&[15, 25, 47, 40][..]));
+        assert_eq!(part2::solve_example(&numbers), Some(62));
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(Some(1398413738)));
+        assert_eq!(part2(INPUT), Ok(Some(169521051)));
+    }
+}
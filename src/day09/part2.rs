@@ -0,0 +1,103 @@
+/* --- Part Two ---
+
+The final step in breaking the XMAS encryption relies on the invalid number you just found: you must find a contiguous set of at least two numbers in your list which sum to the invalid number from step 1.
+
+Again consider the above example:
+
+35
+20
+15
+25
+47
+40
+62
+55
+65
+95
+102
+117
+150
+182
+127
+219
+299
+277
+309
+576
+
+In this list, adding up all of the numbers from 15 through 40 produces the invalid number from step 1, 127. (Of course, the contiguous set of numbers in your actual list might be much longer.)
+
+To find the encryption weakness, add together the smallest and largest number in this contiguous range; in this example, these are 15 and 47, producing 62.
+
+What is the encryption weakness in your XMAS-encrypted list of numbers?
+*/
+use crate::parse::ParseError;
+use crate::registry::Details;
+
+use super::{parse, part1, EXAMPLE_PREAMBLE_SIZE, PREAMBLE_SIZE};
+
+pub fn part2(input: &str) -> Result<Option<i64>, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn solve(input: &[i64]) -> Option<i64> {
+    find_weakness(input, PREAMBLE_SIZE)
+}
+
+pub fn solve_example(input: &[i64]) -> Option<i64> {
+    find_weakness(input, EXAMPLE_PREAMBLE_SIZE)
+}
+
+pub fn details(input: &[i64]) -> Details {
+    let set = part1::find_noncompliant(input.iter().copied(), PREAMBLE_SIZE)
+        .and_then(|target| find_contiguous_set(input, target));
+
+    match set {
+        Some(set) => vec![
+            ("invalid_number", set.iter().sum::<i64>().to_string()),
+            ("set_length", set.len().to_string()),
+            ("min", set.iter().min().unwrap().to_string()),
+            ("max", set.iter().max().unwrap().to_string())
+        ],
+        None => vec![]
+    }
+}
+
+// the sum of the smallest and largest numbers of the contiguous set summing to
+// the result from part 1
+pub fn find_weakness(input: &[i64], preamble_size: usize) -> Option<i64> {
+    let target = part1::find_noncompliant(input.iter().copied(), preamble_size)?;
+    let slice = find_contiguous_set(input, target)?;
+
+    let min = *slice.iter().min()?;
+    let max = *slice.iter().max()?;
+
+    Some(min + max)
+}
+
+pub fn find_contiguous_set(numbers: &[i64], target: i64) -> Option<&[i64]> {
+    const MIN_SLICE_SIZE: usize = 2;
+
+    let mut left_index = 0;
+    let mut right_index = MIN_SLICE_SIZE - 1;
+    let mut rolling_sum = numbers.iter().take(MIN_SLICE_SIZE).sum::<i64>();
+
+    loop {
+        if right_index >= numbers.len() {
+            return None;
+        }
+
+        if rolling_sum == target {
+            return Some(&numbers[left_index..(1 + right_index)]);
+        } else if rolling_sum > target && right_index - left_index > (MIN_SLICE_SIZE - 1) {
+            rolling_sum -= numbers[left_index];
+            left_index += 1;
+        } else {
+            right_index += 1;
+
+            if let Some(number) = numbers.get(right_index) {
+                rolling_sum += number;
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::parse::{parse_integers, ParseError};
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+
+// returns the joltages, sorted
+pub fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+    let mut joltages = parse_integers(input, "a joltage rating")?;
+
+    joltages.sort_unstable();
+
+    Ok(joltages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "16\n10\n15\n5\n1\n11\n7\n19\n6\n12\n4\n";
+
+    const LARGER_EXAMPLE: &str = "\
+28\n33\n18\n42\n31\n14\n46\n20\n48\n47\n24\n23\n49\n45\n19\n38\n39\n11\n1\n32\n25\n35\n8\n17\n7\n9\n4\n2\n34\n10\n3\n";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1::count_differences(&parse(EXAMPLE).unwrap()), [0, 7, 0, 5]);
+        assert_eq!(part1::count_differences(&parse(LARGER_EXAMPLE).unwrap()), [0, 22, 0, 10]);
+        assert_eq!(part1(LARGER_EXAMPLE), Ok(220));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EXAMPLE), Ok(8));
+        assert_eq!(part2(LARGER_EXAMPLE), Ok(19208));
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(2312));
+        assert_eq!(part2(INPUT), Ok(12089663946752));
+    }
+}
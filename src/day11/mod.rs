@@ -0,0 +1,168 @@
+use std::convert::TryFrom;
+use std::fmt::Display;
+
+use crate::grid::Grid;
+use crate::parse::ParseError;
+use crate::trace::Observer;
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+
+pub fn parse(input: &str) -> Result<Seats, ParseError> {
+    let seats = Grid::parse(input, "`L`, `#` or `.`")?;
+
+    Ok(Seats { seats })
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Seats {
+    pub seats: Grid<Seat>
+}
+
+impl Seats {
+    // applies iterate_seat to every seat at once
+    pub fn iterate<F>(&self, iterate_seat: F) -> Seats
+    where F: Fn(&Seats, usize, usize) -> Seat {
+        let new_seats = Grid::from_fn(self.seats.width(), self.seats.height(), |row, col| {
+            iterate_seat(self, row, col)
+        });
+
+        Seats { seats: new_seats }
+    }
+
+    // iterates until nothing changes
+    pub fn stabilize<F>(self, iterate_seat: F) -> Seats
+    where F: Fn(&Seats, usize, usize) -> Seat {
+        self.stabilize_observed(iterate_seat, &mut ())
+    }
+
+    // as stabilize, showing the observer the seats after each round that
+    // changes them
+    pub fn stabilize_observed<F>(self, iterate_seat: F, observer: &mut dyn Observer) -> Seats
+    where F: Fn(&Seats, usize, usize) -> Seat {
+        let mut seats = self;
+
+        loop {
+            let new_seats = seats.iterate(&iterate_seat);
+
+            if new_seats == seats {
+                return seats;
+            } else {
+                observer.step(&new_seats);
+                seats = new_seats
+            }
+        }
+    }
+
+    pub fn occupied_count(&self) -> usize {
+        self.seats.iter()
+            .filter(|s| **s == Seat::Occupied)
+            .count()
+    }
+}
+
+impl Display for Seats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.seats)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Seat {
+    Empty,
+    Occupied,
+    Floor
+}
+
+impl TryFrom<char> for Seat {
+    type Error = char;
+
+    fn try_from(c: char) -> Result<Self, char> {
+        match c {
+            '#' => Ok(Seat::Occupied),
+            '.' => Ok(Seat::Floor),
+            'L' => Ok(Seat::Empty),
+            _ => Err(c)
+        }
+    }
+}
+
+impl From<Seat> for char {
+    fn from(s: Seat) -> Self {
+        match s {
+            Seat::Empty => 'L',
+            Seat::Occupied => '#',
+            Seat::Floor => '.'
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::{Frames, StepCounter};
+
+    const EXAMPLE: &str = "\
+L.LL.LL.LL
+LLLLLLL.LL
+L.L.L..L..
+LLLL.LL.LL
+L.LL.LL.LL
+L.LLLLL.LL
+..L.L.....
+LLLLLLLLLL
+L.LLLLLL.L
+L.LLLLL.LL
+";
+
+    #[test]
+    fn part1_example() {
+        let seats = parse(EXAMPLE).unwrap();
+
+        // everyone sits down in the first round
+        let first_round = seats.iterate(part1::iterate_seat);
+        assert_eq!(first_round.to_string(), EXAMPLE.replace('L', "#"));
+
+        assert_eq!(part1::solve(&seats), 37);
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EXAMPLE), Ok(26));
+    }
+
+    #[test]
+    fn traced_rounds() {
+        let seats = parse(EXAMPLE).unwrap();
+
+        // the statement shows five rounds before the seats stop changing
+        let mut frames = Frames::default();
+        assert_eq!(part1::trace(&seats, &mut frames), 37);
+        assert_eq!(frames.frames.len(), 5);
+        assert_eq!(frames.frames[0], EXAMPLE.replace('L', "#"));
+
+        let mut counter = StepCounter::default();
+        assert_eq!(part2::trace(&seats, &mut counter), 26);
+        assert_eq!(counter.steps, 6);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = parse("L.L\nL?L\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: expected `L`, `#` or `.`, found `?`");
+
+        let error = parse("L.L\nL.\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a row 3 wide, found `L.`");
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(2453));
+        assert_eq!(part2(INPUT), Ok(2159));
+    }
+}
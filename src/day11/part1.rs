@@ -0,0 +1,28 @@
+use crate::parse::ParseError;
+use crate::trace::Observer;
+
+use super::{parse, Seat, Seats};
+
+pub fn part1(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn solve(seats: &Seats) -> usize {
+    trace(seats, &mut ())
+}
+
+pub fn trace(seats: &Seats, observer: &mut dyn Observer) -> usize {
+    seats.clone().stabilize_observed(iterate_seat, observer).occupied_count()
+}
+
+pub fn iterate_seat(seats: &Seats, row: usize, col: usize) -> Seat {
+    let occupied_count = seats.seats.neighbours(row, col)
+        .filter(|&&seat| seat == Seat::Occupied)
+        .count();
+
+    match seats.seats[(row, col)] {
+        Seat::Occupied if occupied_count >= 4 => Seat::Empty,
+        Seat::Empty if occupied_count == 0 => Seat::Occupied,
+        seat => seat
+    }
+}
@@ -0,0 +1,40 @@
+use crate::grid::{Direction, ADJACENT};
+use crate::parse::ParseError;
+use crate::trace::Observer;
+
+use super::{parse, Seat, Seats};
+
+pub fn part2(input: &str) -> Result<usize, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn solve(seats: &Seats) -> usize {
+    trace(seats, &mut ())
+}
+
+pub fn trace(seats: &Seats, observer: &mut dyn Observer) -> usize {
+    seats.clone().stabilize_observed(iterate_seat, observer).occupied_count()
+}
+
+// the first seat seen looking from (row, col) in direction, over the floor
+pub fn first_seat_from(seats: &Seats, row: usize, col: usize, direction: Direction) -> Option<Seat> {
+    seats.seats.ray(row, col, direction)
+        .copied()
+        .find(|&seat| seat != Seat::Floor)
+}
+
+pub fn iterate_seat(seats: &Seats, row: usize, col: usize) -> Seat {
+    if let Seat::Floor = seats.seats[(row, col)] {
+        return Seat::Floor;
+    }
+
+    let occupied_count = ADJACENT.iter()
+        .filter(|&&direction| first_seat_from(seats, row, col, direction) == Some(Seat::Occupied))
+        .count();
+
+    match seats.seats[(row, col)] {
+        Seat::Occupied if occupied_count >= 5 => Seat::Empty,
+        Seat::Empty if occupied_count == 0 => Seat::Occupied,
+        seat => seat
+    }
+}
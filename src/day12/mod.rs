@@ -0,0 +1,151 @@
+use crate::parse::{parse_number, LineError};
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+
+use Heading::*;
+
+pub const ACTIONS: &str = "NSEWLRF";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Action {
+    Move(Heading, i32),
+    Turn(Turn),
+    Forward(i32)
+}
+
+// splits a navigation instruction into its action and value
+pub fn parse_action(s: &str) -> Result<Action, LineError> {
+    let action = match s.chars().next() {
+        Some(c) if ACTIONS.contains(c) => c,
+        c => return Err(LineError::new(0, c.map_or(0, char::len_utf8), "an action: `N`, `S`, `E`, `W`, `L`, `R` or `F`"))
+    };
+
+    let value = match parse_number::<i32>(&s[1..], "a non-negative integer") {
+        Ok(value) if value >= 0 => value,
+        _ => return Err(LineError::new(1, s.len() - 1, "a non-negative integer"))
+    };
+
+    let turn = |turn: Option<Turn>| turn
+        .map(Action::Turn)
+        .ok_or_else(|| LineError::new(1, s.len() - 1, "a multiple of 90 degrees, up to 360"));
+
+    match action {
+        'N' => Ok(Action::Move(North, value)),
+        'S' => Ok(Action::Move(South, value)),
+        'E' => Ok(Action::Move(East, value)),
+        'W' => Ok(Action::Move(West, value)),
+        'L' => turn(Turn::left(value)),
+        'R' => turn(Turn::right(value)),
+        _ => Ok(Action::Forward(value))
+    }
+}
+
+// a turn to the right by a number of quarter turns, less than a whole turn
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Turn(u8);
+
+impl Turn {
+    // None unless degrees is a multiple of 90, up to 360
+    pub fn right(degrees: i32) -> Option<Turn> {
+        if (0..=360).contains(&degrees) && degrees % 90 == 0 {
+            Some(Turn((degrees / 90 % 4) as u8))
+        } else {
+            None
+        }
+    }
+
+    pub fn left(degrees: i32) -> Option<Turn> {
+        Self::right(degrees).map(|Turn(quarters)| Turn((4 - quarters) % 4))
+    }
+
+    pub fn quarters(self) -> usize {
+        usize::from(self.0)
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Heading {
+    North, East, South, West
+}
+
+impl Heading {
+    pub fn turn(&self, turn: Turn) -> Heading {
+        const HEADINGS: &[Heading] = &[North, East, South, West];
+
+        let offset = HEADINGS.iter().position(|h| h == self).unwrap();
+
+        HEADINGS[(offset + turn.quarters()) % 4]
+    }
+
+    // (dx, dy) of moving one unit in this direction
+    pub fn delta(&self) -> (i32, i32) {
+        match self {
+            North => (0, 1),
+            East => (1, 0),
+            South => (0, -1),
+            West => (-1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::Frames;
+
+    const EXAMPLE: &str = "F10\nN3\nF7\nR90\nF11\n";
+
+    #[test]
+    fn turn() {
+        assert_eq!(East.turn(Turn::right(90).unwrap()), South);
+        assert_eq!(North.turn(Turn::right(270).unwrap()), West);
+        assert_eq!(West.turn(Turn::right(360).unwrap()), West);
+        assert_eq!(North.turn(Turn::left(90).unwrap()), West);
+
+        assert_eq!(Turn::right(45), None);
+        assert_eq!(Turn::left(450), None);
+    }
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EXAMPLE), Ok(25));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EXAMPLE), Ok(286));
+    }
+
+    #[test]
+    fn traced_instructions() {
+        let mut frames = Frames::default();
+        assert_eq!(part1::trace(&part1::parse(EXAMPLE).unwrap(), &mut frames), 25);
+        assert_eq!(frames.frames.len(), 5);
+        assert_eq!(frames.frames[0], "MoveForward(10): ship at (10, 0) facing East");
+
+        let mut frames = Frames::default();
+        assert_eq!(part2::trace(&part2::parse(EXAMPLE).unwrap(), &mut frames), 286);
+        assert_eq!(frames.frames.len(), 5);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = part1::parse("F10\nN3\nX7\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 3, column 1: expected an action: `N`, `S`, `E`, `W`, `L`, `R` or `F`, found `X`");
+
+        let error = part2::parse("F10\nR45\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 2: expected a multiple of 90 degrees, up to 360, found `45`");
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(879));
+        assert_eq!(part2(INPUT), Ok(18107));
+    }
+}
@@ -0,0 +1,138 @@
+/* --- Day 12: Rain Risk ---
+
+Your ferry made decent progress toward the island, but the storm came in faster than anyone expected. The ferry needs to take evasive actions!
+
+Unfortunately, the ship's navigation computer seems to be malfunctioning; rather than giving a route directly to safety, it produced extremely circuitous instructions. When the captain uses the PA system to ask if anyone can help, you quickly volunteer.
+
+The navigation instructions (your puzzle input) consists of a sequence of single-character actions paired with integer input values. After staring at them for a few minutes, you work out what they probably mean:
+
+    Action N means to move north by the given value.
+    Action S means to move south by the given value.
+    Action E means to move east by the given value.
+    Action W means to move west by the given value.
+    Action L means to turn left the given number of degrees.
+    Action R means to turn right the given number of degrees.
+    Action F means to move forward by the given value in the direction the ship is currently facing.
+
+The ship starts by facing east. Only the L and R actions change the direction the ship is facing. (That is, if the ship is facing east and the next instruction is N10, the ship would move north 10 units, but would still move east if the following action were F.)
+
+For example:
+
+F10
+N3
+F7
+R90
+F11
+
+These instructions would be handled as follows:
+
+    F10 would move the ship 10 units east (because the ship starts by facing east) to east 10, north 0.
+    N3 would move the ship 3 units north to east 10, north 3.
+    F7 would move the ship another 7 units east (because the ship is still facing east) to east 17, north 3.
+    R90 would cause the ship to turn right by 90 degrees and face south; it remains at east 17, north 3.
+    F11 would move the ship 11 units south to east 17, south 8.
+
+At the end of these instructions, the ship's Manhattan distance (sum of the absolute values of its east/west position and its north/south position) from its starting position is 17 + 8 = 25.
+
+Figure out where the navigation instructions lead. What is the Manhattan distance between that location and the ship's starting position?
+*/
+use crate::parse::{parse_lines, LineError, ParseError};
+use crate::registry::Details;
+use crate::trace::Observer;
+
+use super::{parse_action, Action, Turn};
+use super::Heading::{self, *};
+
+pub fn part1(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, parse_instruction)
+}
+
+// manhattan distance of the ship from its starting point
+pub fn solve(instructions: &[Instruction]) -> i32 {
+    trace(instructions, &mut ())
+}
+
+pub fn trace(instructions: &[Instruction], observer: &mut dyn Observer) -> i32 {
+    let ship = sail_observed(instructions, observer);
+
+    ship.x.abs() + ship.y.abs()
+}
+
+pub fn details(instructions: &[Instruction]) -> Details {
+    let ship = sail(instructions);
+
+    vec![("ship_location", format!("({}, {})", ship.x, ship.y))]
+}
+
+pub fn sail(instructions: &[Instruction]) -> Ship {
+    sail_observed(instructions, &mut ())
+}
+
+// as sail, showing the observer the ship after each instruction
+pub fn sail_observed(instructions: &[Instruction], observer: &mut dyn Observer) -> Ship {
+    let mut ship = Ship::new();
+
+    for &instruction in instructions {
+        ship.act(instruction);
+
+        observer.step(&format_args!(
+            "{:?}: ship at ({}, {}) facing {:?}", instruction, ship.x, ship.y, ship.heading
+        ));
+    }
+
+    ship
+}
+
+pub fn parse_instruction(s: &str) -> Result<Instruction, LineError> {
+    Ok(match parse_action(s.trim_end())? {
+        Action::Move(heading, units) => Instruction::Move(heading, units),
+        Action::Turn(turn) => Instruction::TurnRight(turn),
+        Action::Forward(units) => Instruction::MoveForward(units)
+    })
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Ship {
+    pub x: i32,
+    pub y: i32,
+
+    pub heading: Heading
+}
+
+impl Ship {
+    pub fn new() -> Self {
+        Self { x: 0, y: 0, heading: East }
+    }
+
+    pub fn act(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::Move(heading, units) => self.move_to(heading, units),
+            Instruction::MoveForward(units) => self.move_to(self.heading, units),
+            Instruction::TurnRight(turn) => self.heading = self.heading.turn(turn)
+        }
+    }
+
+    fn move_to(&mut self, heading: Heading, units: i32) {
+        let (dx, dy) = heading.delta();
+
+        self.x += units * dx;
+        self.y += units * dy;
+    }
+}
+
+impl Default for Ship {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Instruction {
+    Move(Heading, i32),
+    MoveForward(i32),
+    TurnRight(Turn)
+}
@@ -0,0 +1,146 @@
+/* --- Part Two ---
+
+Before you can give the destination to the captain, you realize that the actual action meanings were printed on the back of the instructions the whole time.
+
+Almost all of the actions indicate how to move a waypoint which is relative to the ship's position:
+
+    Action N means to move the waypoint north by the given value.
+    Action S means to move the waypoint south by the given value.
+    Action E means to move the waypoint east by the given value.
+    Action W means to move the waypoint west by the given value.
+    Action L means to rotate the waypoint around the ship left (counter-clockwise) the given number of degrees.
+    Action R means to rotate the waypoint around the ship right (clockwise) the given number of degrees.
+    Action F means to move forward to the waypoint a number of times equal to the given value.
+
+The waypoint starts 10 units east and 1 unit north relative to the ship. The waypoint is relative to the ship; that is, if the ship moves, the waypoint moves with it.
+
+For example, using the same instructions as above:
+
+    F10 moves the ship to the waypoint 10 times (a total of 100 units east and 10 units north), leaving the ship at east 100, north 10. The waypoint stays 10 units east and 1 unit north of the ship.
+    N3 moves the waypoint 3 units north to 10 units east and 4 units north of the ship. The ship remains at east 100, north 10.
+    F7 moves the ship to the waypoint 7 times (a total of 70 units east and 28 units north), leaving the ship at east 170, north 38. The waypoint stays 10 units east and 4 units north of the ship.
+    R90 rotates the waypoint around the ship clockwise 90 degrees, moving it to 4 units east and 10 units south of the ship. The ship remains at east 170, north 38.
+    F11 moves the ship to the waypoint 11 times (a total of 44 units east and 110 units south), leaving the ship at east 214, south 72. The waypoint stays 4 units east and 10 units south of the ship.
+
+After these operations, the ship's Manhattan distance from its starting position is 214 + 72 = 286.
+
+Figure out where the navigation instructions actually lead. What is the Manhattan distance between that location and the ship's starting position?
+*/
+use crate::parse::{parse_lines, LineError, ParseError};
+use crate::registry::Details;
+use crate::trace::Observer;
+
+use super::{parse_action, Action, Turn};
+use super::Heading;
+
+pub fn part2(input: &str) -> Result<i32, ParseError> {
+    Ok(solve(&parse(input)?))
+}
+
+pub fn parse(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parse_lines(input, parse_instruction)
+}
+
+// manhattan distance of the ship from its starting point
+pub fn solve(instructions: &[Instruction]) -> i32 {
+    trace(instructions, &mut ())
+}
+
+pub fn trace(instructions: &[Instruction], observer: &mut dyn Observer) -> i32 {
+    let ship = sail_observed(instructions, observer);
+
+    ship.x.abs() + ship.y.abs()
+}
+
+pub fn details(instructions: &[Instruction]) -> Details {
+    let ship = sail(instructions);
+
+    vec![("ship_location", format!("({}, {})", ship.x, ship.y))]
+}
+
+pub fn sail(instructions: &[Instruction]) -> Ship {
+    sail_observed(instructions, &mut ())
+}
+
+// as sail, showing the observer the ship after each instruction
+pub fn sail_observed(instructions: &[Instruction], observer: &mut dyn Observer) -> Ship {
+    let mut ship = Ship::new();
+
+    for &instruction in instructions {
+        ship.act(instruction);
+
+        observer.step(&format_args!(
+            "{:?}: ship at ({}, {}), waypoint at ({}, {})",
+            instruction, ship.x, ship.y, ship.waypoint_x, ship.waypoint_y
+        ));
+    }
+
+    ship
+}
+
+pub fn parse_instruction(s: &str) -> Result<Instruction, LineError> {
+    Ok(match parse_action(s.trim_end())? {
+        Action::Move(heading, units) => Instruction::MoveWaypoint(heading, units),
+        Action::Turn(turn) => Instruction::TurnWaypointRight(turn),
+        Action::Forward(times) => Instruction::MoveForward(times as usize)
+    })
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Ship {
+    pub x: i32,
+    pub y: i32,
+
+    pub waypoint_x: i32,
+    pub waypoint_y: i32,
+}
+
+impl Ship {
+    pub fn new() -> Self {
+        Self { x: 0, y: 0, waypoint_x: 10, waypoint_y: 1 }
+    }
+
+    pub fn act(&mut self, instruction: Instruction) {
+        match instruction {
+            Instruction::MoveWaypoint(heading, units) => self.move_waypoint(heading, units),
+            Instruction::MoveForward(times) => self.move_ship(times),
+            Instruction::TurnWaypointRight(turn) => self.rotate_waypoint_right(turn)
+        }
+    }
+
+    fn move_waypoint(&mut self, heading: Heading, units: i32) {
+        let (dx, dy) = heading.delta();
+
+        self.waypoint_x += units * dx;
+        self.waypoint_y += units * dy;
+    }
+
+    fn move_ship(&mut self, times: usize) {
+        for _ in 0..times {
+            self.x += self.waypoint_x;
+            self.y += self.waypoint_y;
+        }
+    }
+
+    fn rotate_waypoint_right(&mut self, turn: Turn) {
+        for _ in 0..turn.quarters() {
+            let (x, y) = (self.waypoint_x, self.waypoint_y);
+
+            self.waypoint_x = y;
+            self.waypoint_y = -x;
+        }
+    }
+}
+
+impl Default for Ship {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Instruction {
+    MoveWaypoint(Heading, i32),
+    MoveForward(usize),
+    TurnWaypointRight(Turn)
+}
@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use crate::parse::{numbered_lines, parse_comma_list, parse_number, LineError, ParseError};
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!("input");
+
+// returns the earliest time we could depart, and the id of each bus in the
+// order listed, or None for each `x`
+pub fn parse_notes<T>(input: &str) -> Result<(T, Vec<Option<T>>), ParseError>
+where T: FromStr + PartialOrd + From<u8> {
+    let mut lines = numbered_lines(input);
+
+    let (number, line) = lines.next().unwrap_or((1, ""));
+    let current_time = parse_number(line, "the earliest departure time")
+        .map_err(|e| e.at_line(number, line))?;
+
+    let (number, line) = lines.next().unwrap_or((number + 1, ""));
+    let buses = parse_buses(line)
+        .map_err(|e| e.at_line(number, line))?;
+
+    Ok((current_time, buses))
+}
+
+fn parse_buses<T>(line: &str) -> Result<Vec<Option<T>>, LineError>
+where T: FromStr + PartialOrd + From<u8> {
+    parse_comma_list(line, "x", |item| match parse_number::<T>(item, "a bus id or `x`")? {
+        id if id > T::from(0) => Ok(id),
+        _ => Err(LineError::whole(item, "a bus id above 0"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::SolveError;
+    use crate::trace::Frames;
+
+    const EXAMPLE: &str = "939\n7,13,x,x,59,x,31,19\n";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1::closest_bus(939, &[7, 13, 59, 31, 19]), Some((59, 5)));
+        assert_eq!(part1(EXAMPLE), Ok(Some(295)));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EXAMPLE), Ok(Some(1068781)));
+
+        // only the second line matters for part 2
+        assert_eq!(part2("0\n17,x,13,19"), Ok(Some(3417)));
+        assert_eq!(part2("0\n67,7,59,61"), Ok(Some(754018)));
+        assert_eq!(part2("0\n67,x,7,59,61"), Ok(Some(779210)));
+        assert_eq!(part2("0\n67,7,x,59,61"), Ok(Some(1261476)));
+        assert_eq!(part2("0\n1789,37,47,1889"), Ok(Some(1202161486)));
+    }
+
+    #[test]
+    fn part2_without_a_timestamp() {
+        // 4 and 6 only ever depart at even timestamps, a minute can't separate them
+        assert_eq!(part2("0\n4,6"), Ok(None));
+        assert_eq!(part2("0\n4,x,6"), Ok(Some(4)));
+        assert_eq!(part2::diophantus_combine((4, 0), (6, -1)), Err(part2::CombineError::Unsolvable));
+
+        let error = part2("0\n1000000007,1000000009,999999937").unwrap_err();
+        assert!(matches!(error, SolveError::Overflow(_)), "{}", error);
+    }
+
+    #[test]
+    fn traced_buses() {
+        // one step per bus in service
+        let mut frames = Frames::default();
+        assert_eq!(part2::trace(&part2::parse("0\n17,x,13,19").unwrap(), &mut frames), Ok(Some(3417)));
+        assert_eq!(frames.frames.len(), 3);
+        assert_eq!(frames.frames.last().unwrap(), "timestamps 3417 + 4199 * t");
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = part1::parse("939\n7,13,x,0,59\n").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 8: expected a bus id above 0, found `0`");
+
+        let error = part2::parse("939").unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a bus id or `x`, found end of line");
+    }
+
+    #[test]
+    fn bundled_input() {
+        assert_eq!(part1(INPUT), Ok(Some(2238)));
+        assert_eq!(part2(INPUT), Ok(Some(560214575859998)));
+    }
+}
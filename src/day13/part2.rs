@@ -0,0 +1,203 @@
+/* --- Part Two ---
+
+The shuttle company is running a contest: one gold coin for anyone that can find the earliest timestamp such that the first bus ID departs at that time and each subsequent listed bus ID departs at that subsequent minute. (The first line in your input is no longer relevant.)
+
+For example, suppose you have the same list of bus IDs as above:
+
+7,13,x,x,59,x,31,19
+
+An x in the schedule means there are no constraints on what bus IDs must depart at that time.
+
+This means you are looking for the earliest timestamp (called t) such that:
+
+    Bus ID 7 departs at timestamp t.
+    Bus ID 13 departs one minute after timestamp t.
+    There are no requirements or restrictions on departures at two or three minutes after timestamp t.
+    Bus ID 59 departs four minutes after timestamp t.
+    There are no requirements or restrictions on departures at five minutes after timestamp t.
+    Bus ID 31 departs six minutes after timestamp t.
+    Bus ID 19 departs seven minutes after timestamp t.
+
+The only bus departures that matter are the listed bus IDs at their specific offsets from t. Those bus IDs can depart at other times, and other bus IDs can depart at those times. For example, in the list above, because bus ID 19 must depart seven minutes after the timestamp at which bus ID 7 departs, bus ID 7 will always also be departing with bus ID 19 at seven minutes after timestamp t.
+
+In this example, the earliest timestamp at which this occurs is 1068781:
+
+time     bus 7   bus 13  bus 59  bus 31  bus 19
+1068773    .       .       .       .       .
+1068774    D       .       .       .       .
+1068775    .       .       .       .       .
+1068776    .       .       .       .       .
+1068777    .       .       .       .       .
+1068778    .       .       .       .       .
+1068779    .       .       .       .       .
+1068780    .       .       .       .       .
+1068781    D       .       .       .       .
+1068782    .       D       .       .       .
+1068783    .       .       .       .       .
+1068784    .       .       .       .       .
+1068785    .       .       D       .       .
+1068786    .       .       .       .       .
+1068787    .       .       .       D       .
+1068788    D       .       .       .       D
+1068789    .       .       .       .       .
+1068790    .       .       .       .       .
+1068791    .       .       .       .       .
+1068792    .       .       .       .       .
+1068793    .       .       .       .       .
+1068794    .       .       .       .       .
+1068795    D       D       .       .       .
+1068796    .       .       .       .       .
+1068797    .       .       .       .       .
+
+In the above example, bus ID 7 departs at timestamp 1068788 (seven minutes after t). This is fine; the only requirement on that minute is that bus ID 19 departs then, and it does.
+
+Here are some other examples:
+
+    The earliest timestamp that matches the list 17,x,13,19 is 3417.
+    67,7,59,61 first occurs at timestamp 754018.
+    67,x,7,59,61 first occurs at timestamp 779210.
+    67,7,x,59,61 first occurs at timestamp 1261476.
+    1789,37,47,1889 first occurs at timestamp 1202161486.
+
+However, with so many bus IDs in your list, surely the actual earliest timestamp will be larger than 100000000000000!
+
+What is the earliest timestamp such that all of the listed bus IDs depart at offsets matching their positions in the list?
+*/
+use crate::number::Overflow;
+use crate::parse::ParseError;
+use crate::registry::SolveError;
+use crate::trace::Observer;
+
+use super::parse_notes;
+
+pub fn part2(input: &str) -> Result<Option<i64>, SolveError> {
+    Ok(solve(&parse(input)?)?)
+}
+
+// returns each bus as a function f(x) = id * x - offset, where the bus departs
+// at every f(x)
+pub fn parse(input: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let (_, buses) = parse_notes::<i64>(input)?;
+
+    Ok(buses.into_iter()
+        .enumerate()
+        .filter_map(|(index, id)| id.map(|id| (id, -(index as i64))))
+        .collect())
+}
+
+// the earliest timestamp at which the buses depart one after another, or None
+// if they never do
+pub fn solve(buses: &[(i64, i64)]) -> Result<Option<i64>, Overflow> {
+    trace(buses, &mut ())
+}
+
+pub fn trace(buses: &[(i64, i64)], observer: &mut dyn Observer) -> Result<Option<i64>, Overflow> {
+    // Note: this is a complex math problem, which requires a bit of explanation.
+    // I did not solve this myself. I used the solution to the equivalent problem
+    // here: http://mathforum.org/library/drmath/view/51595.html
+    let combined = buses.iter().copied().try_fold((1, 0), |combined, bus| {
+        let combined = diophantus_combine_observed(combined, bus, observer)?;
+        observer.step(&format_args!("timestamps {} + {} * t", combined.1, combined.0));
+
+        Ok(combined)
+    });
+
+    match combined {
+        Ok((_, solution)) => Ok(Some(solution)),
+        Err(CombineError::Unsolvable) => Ok(None),
+        Err(CombineError::Overflow(e)) => Err(e)
+    }
+}
+
+// why two functions can't be combined: they never meet, or where they do
+// doesn't fit in an i64
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum CombineError {
+    Unsolvable,
+    Overflow(Overflow)
+}
+
+fn overflow(operation: &'static str, operands: &[i64]) -> CombineError {
+    CombineError::Overflow(Overflow {
+        operation,
+        operands: operands.iter().map(i64::to_string).collect(),
+        type_name: "i64"
+    })
+}
+
+/// given two functions: f(x) = ax + b, g(x) = cx + d (represented by
+/// x_args = (a, b) and y_args = (c, d)), returns a function: h(x) = kx + l
+/// (represented by (k, l)).
+///
+/// The returned function h(x) satisfies:
+///     for any integer i, there exists integers m, n such that h(i) = f(m) = g(n)
+///
+/// Effectively, h(x) generates integer solutions to f(x) = g(y), and then returns
+/// not x or y, but f(x)
+///
+/// Fails if f(x) = g(y) has no integer solutions, or if h(x) doesn't fit in an i64
+pub fn diophantus_combine(x_args: (i64, i64), y_args: (i64, i64)) -> Result<(i64, i64), CombineError> {
+    diophantus_combine_observed(x_args, y_args, &mut ())
+}
+
+/// as diophantus_combine, noting each stage of the solution to the observer
+pub fn diophantus_combine_observed(
+    x_args: (i64, i64), y_args: (i64, i64), observer: &mut dyn Observer
+) -> Result<(i64, i64), CombineError> {
+    // Sorry for the confusing variable names: this was a math problem
+    let (x1, x0) = x_args;
+    let (y1, y0) = y_args;
+
+    observer.note(&format_args!("solving {}x + {} = {}y + {}", x1, x0, y1, y0));
+
+    let x_ = x1;
+    let y_ = y1.checked_neg().ok_or_else(|| overflow("negation", &[y1]))?;
+    let c = y0.checked_sub(x0).ok_or_else(|| overflow("difference", &[y0, x0]))?;
+
+    observer.note(&format_args!("rewriting into {}x + {}y = {}", x_, y_, c));
+
+    let (x_comb, y_comb) = euclid_combinators(x_, y_);
+
+    // the terms can overflow even though the gcd itself can't
+    let gcd = (i128::from(x_comb) * i128::from(x_) + i128::from(y_comb) * i128::from(y_)) as i64;
+
+    observer.note(&format_args!("found gcd({}, {}) = {}*{} + {}*{} = {}", x_, y_, x_comb, x_, y_comb, y_, gcd));
+
+    // there are only solutions if the gcd divides c
+    if c % gcd != 0 {
+        observer.note(&format_args!("{} doesn't divide {}, so there are no solutions", gcd, c));
+        return Err(CombineError::Unsolvable);
+    }
+
+    // we can get the general solution x = x_comb + (y_ / gcd) * t
+
+    let multiplicand = c / gcd;
+
+    // this isn't needed, but to make the results "pretty", and it keeps t0
+    // small enough to fit back in an i64
+    let t1 = (y_ / gcd).abs();
+    let t0 = (i128::from(x_comb) * i128::from(multiplicand)).rem_euclid(i128::from(t1)) as i64;
+
+    observer.note(&format_args!("general solution: x = {} + {} * t", t0, t1));
+
+    // we then put that back into the original equation x1 * x + x0
+
+    let k = x1.checked_mul(t1).ok_or_else(|| overflow("product", &[x1, t1]))?;
+    let l = x1.checked_mul(t0).ok_or_else(|| overflow("product", &[x1, t0]))?;
+    let l = l.checked_add(x0).ok_or_else(|| overflow("sum", &[l, x0]))?;
+
+    Ok((k, l))
+}
+
+/// Returns the euclid "combinators" (a', b' such that a × a' + b × b' = gcd(a, b))
+pub fn euclid_combinators(a: i64, b: i64) -> (i64, i64) {
+    let div = a / b;
+    let rem = a % b;
+
+    if rem == 0 {
+        (0, 1)
+    } else {
+        let (b_comb, rem_comb) = euclid_combinators(b, rem);
+        (rem_comb, b_comb + rem_comb * -div)
+    }
+}
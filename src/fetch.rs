@@ -0,0 +1,336 @@
+// Downloads puzzle inputs with a session token and caches them per session and
+// server, so that each input is only ever downloaded once, and never mixed up
+// with another account's. Plain http is spoken directly,
+// which is enough for a local stand-in server; https is left to curl, since
+// there is no TLS implementation in the crate.
+use std::env;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com/2020";
+
+const USER_AGENT: &str = concat!("advent-of-code-2020/", env!("CARGO_PKG_VERSION"));
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct Fetcher {
+    // inputs are at <base_url>/day/<day>/input
+    pub base_url: String,
+    pub session: String,
+    pub cache_dir: PathBuf
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Fetched {
+    // already cached, so nothing was downloaded
+    Cached(PathBuf),
+    Downloaded(PathBuf)
+}
+
+// $AOC_CACHE_DIR, or advent-of-code-2020 under $XDG_CACHE_HOME or ~/.cache
+pub fn default_cache_dir() -> Option<PathBuf> {
+    let non_empty = |name| env::var_os(name).filter(|value| !value.is_empty()).map(PathBuf::from);
+
+    if let Some(dir) = non_empty("AOC_CACHE_DIR") {
+        return Some(dir);
+    }
+
+    let cache = non_empty("XDG_CACHE_HOME").or_else(|| non_empty("HOME").map(|home| home.join(".cache")))?;
+
+    Some(cache.join("advent-of-code-2020"))
+}
+
+impl Fetcher {
+    // under a directory named by a hash of the session and the base url, since
+    // inputs differ by account, and the session shouldn't be readable from it
+    pub fn cached_path(&self, day: u32) -> PathBuf {
+        let base_url = self.base_url.trim_end_matches('/');
+        let account = fnv1a(&[base_url.as_bytes(), b"\n", self.session.as_bytes()]);
+
+        self.cache_dir.join(format!("{:016x}", account)).join(format!("day{:02}", day))
+    }
+
+    // returns the cached input if there is one, and downloads it otherwise
+    pub fn fetch(&self, day: u32) -> Result<Fetched, String> {
+        let path = self.cached_path(day);
+
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let url = format!("{}/day/{}/input", self.base_url.trim_end_matches('/'), day);
+        let input = get(&url, &self.session)?;
+
+        if input.trim().is_empty() {
+            return Err(format!("{} returned an empty input", url));
+        }
+
+        let dir = path.parent().unwrap_or(&self.cache_dir);
+        fs::create_dir_all(dir)
+            .map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+
+        // written next to its final place and then renamed, so that an
+        // interrupted download is never mistaken for a cached input
+        let partial = path.with_extension("partial");
+        fs::write(&partial, &input)
+            .and_then(|_| fs::rename(&partial, &path))
+            .map_err(|e| format!("failed to write {}: {}", path.display(), e))?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+// the 64-bit FNV-1a hash of the parts one after another, which unlike the
+// hasher of std is the same on every version of Rust, so the cache stays put
+fn fnv1a(parts: &[&[u8]]) -> u64 {
+    parts.iter()
+        .flat_map(|part| part.iter())
+        .fold(0xcbf2_9ce4_8422_2325, |hash, &byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3))
+}
+
+fn get(url: &str, session: &str) -> Result<String, String> {
+    match url.split_once("://") {
+        Some(("http", rest)) => http_get(rest, session),
+        Some(("https", _)) => curl_get(url, session),
+        _ => Err(format!("unsupported url: {}, must start with http:// or https://", url))
+    }
+}
+
+// rest is the url without its http:// prefix
+fn http_get(rest: &str, session: &str) -> Result<String, String> {
+    let (authority, path) = match rest.find('/') {
+        Some(index) => rest.split_at(index),
+        None => (rest, "/")
+    };
+
+    let address = if authority.contains(':') { authority.to_owned() } else { format!("{}:80", authority) };
+
+    let connect_error = |e: std::io::Error| format!("failed to connect to {}: {}", authority, e);
+    let mut stream = TcpStream::connect(&address).map_err(connect_error)?;
+    stream.set_read_timeout(Some(TIMEOUT)).map_err(connect_error)?;
+
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nCookie: session={}\r\nUser-Agent: {}\r\nConnection: close\r\n\r\n",
+        path, authority, session, USER_AGENT
+    );
+
+    let mut response = vec![];
+    stream.write_all(request.as_bytes())
+        .and_then(|_| stream.read_to_end(&mut response))
+        .map_err(|e| format!("request to {} failed: {}", authority, e))?;
+
+    let (status, body) = parse_response(&response)?;
+
+    check_status(status, &body)?;
+
+    Ok(body)
+}
+
+// returns the status code and the body
+fn parse_response(response: &[u8]) -> Result<(u16, String), String> {
+    let malformed = || "malformed http response".to_owned();
+
+    let split = response.windows(4).position(|w| w == b"\r\n\r\n").ok_or_else(malformed)?;
+    let head = String::from_utf8_lossy(&response[..split]);
+    let body = &response[(split + 4)..];
+
+    let mut lines = head.split("\r\n");
+
+    let status = lines.next()
+        .and_then(|line| line.split(' ').nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(malformed)?;
+
+    let chunked = lines
+        .filter_map(|line| line.split_once(':'))
+        .any(|(name, value)| {
+            name.trim().eq_ignore_ascii_case("transfer-encoding") && value.trim().eq_ignore_ascii_case("chunked")
+        });
+
+    let body = if chunked { dechunk(body).ok_or_else(malformed)? } else { body.to_vec() };
+
+    String::from_utf8(body)
+        .map(|body| (status, body))
+        .map_err(|_| "the response is not valid UTF-8".to_owned())
+}
+
+fn dechunk(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut result = vec![];
+
+    loop {
+        let line_end = body.windows(2).position(|w| w == b"\r\n")?;
+        let size = std::str::from_utf8(&body[..line_end]).ok()?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+
+        body = &body[(line_end + 2)..];
+
+        if size == 0 {
+            return Some(result);
+        }
+
+        result.extend_from_slice(body.get(..size)?);
+        body = body.get((size + 2)..)?;
+    }
+}
+
+fn check_status(status: u16, body: &str) -> Result<(), String> {
+    let reason = body.lines().next().unwrap_or("").trim();
+
+    match status {
+        200 => Ok(()),
+        400 | 401 | 403 => Err(format!("the session token was rejected ({}): {}", status, reason)),
+        404 => Err(format!("no input found (404): {}", reason)),
+        _ => Err(format!("the server returned {}: {}", status, reason))
+    }
+}
+
+fn curl_get(url: &str, session: &str) -> Result<String, String> {
+    // the cookie is passed on stdin, to keep it out of the process list
+    let mut child = Command::new("curl")
+        .args(["--silent", "--show-error", "--location", "--header", "@-", "--user-agent", USER_AGENT])
+        .args(["--write-out", "\n%{http_code}", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("failed to run curl, which is needed for https: {}", e))?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        writeln!(stdin, "Cookie: session={}", session).map_err(|e| format!("failed to run curl: {}", e))?;
+    }
+
+    let output = child.wait_with_output().map_err(|e| format!("failed to run curl: {}", e))?;
+
+    if !output.status.success() {
+        return Err(format!("curl failed: {}", String::from_utf8_lossy(&output.stderr).trim()));
+    }
+
+    let output = String::from_utf8(output.stdout).map_err(|_| "the response is not valid UTF-8".to_owned())?;
+    let (body, status) = output.rsplit_once('\n').ok_or("curl returned no status")?;
+    let status = status.trim().parse::<u16>().map_err(|_| "curl returned no status".to_owned())?;
+
+    check_status(status, body)?;
+
+    Ok(body.to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    // serves one canned response to each of the given number of connections,
+    // returning the requests it received
+    fn serve(responses: Vec<&'static str>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/2020", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses.into_iter().map(|response| {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = vec![];
+                let mut buffer = [0; 1024];
+
+                while !request.ends_with(b"\r\n\r\n") {
+                    let read = stream.read(&mut buffer).unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                }
+
+                stream.write_all(response.as_bytes()).unwrap();
+                String::from_utf8(request).unwrap()
+            }).collect()
+        });
+
+        (base_url, handle)
+    }
+
+    fn fetcher(base_url: String, name: &str) -> Fetcher {
+        let cache_dir = env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+
+        Fetcher { base_url, session: "53551on".to_owned(), cache_dir }
+    }
+
+    #[test]
+    fn downloads_once_then_uses_the_cache() {
+        let (base_url, server) = serve(vec![
+            "HTTP/1.1 200 OK\r\nContent-Length: 10\r\nConnection: close\r\n\r\n1721\n979\n"
+        ]);
+        let fetcher = fetcher(base_url, "cache");
+        let path = fetcher.cached_path(1);
+
+        assert_eq!(fetcher.fetch(1), Ok(Fetched::Downloaded(path.clone())));
+        assert_eq!(fetcher.fetch(1), Ok(Fetched::Cached(path.clone())));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1721\n979\n");
+
+        // the server only ever answers one request
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2020/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("\r\nCookie: session=53551on\r\n"));
+
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn chunked_response() {
+        let (base_url, server) = serve(vec![
+            "HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n5\r\n1721\n\r\n4\r\n979\n\r\n0\r\n\r\n"
+        ]);
+        let fetcher = fetcher(base_url, "chunked");
+
+        fetcher.fetch(1).unwrap();
+        assert_eq!(fs::read_to_string(fetcher.cached_path(1)).unwrap(), "1721\n979\n");
+
+        server.join().unwrap();
+        fs::remove_dir_all(&fetcher.cache_dir).unwrap();
+    }
+
+    #[test]
+    fn errors_are_not_cached() {
+        let (base_url, server) = serve(vec![
+            "HTTP/1.1 400 Bad Request\r\nContent-Length: 51\r\n\r\nPuzzle inputs differ by user.  Please log in to get",
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 9\r\n\r\nNot Found"
+        ]);
+        let fetcher = fetcher(base_url, "errors");
+
+        let error = fetcher.fetch(3).unwrap_err();
+        assert!(error.starts_with("the session token was rejected (400)"), "{}", error);
+
+        let error = fetcher.fetch(26).unwrap_err();
+        assert_eq!(error, "no input found (404): Not Found");
+
+        assert!(!fetcher.cached_path(3).exists());
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn cached_per_account() {
+        let path = |base_url: &str, session: &str| {
+            let fetcher = Fetcher { base_url: base_url.to_owned(), session: session.to_owned(), cache_dir: PathBuf::from("cache") };
+            fetcher.cached_path(1)
+        };
+
+        let mine = path("http://localhost/2020", "53551on");
+        assert!(mine.starts_with("cache") && mine.ends_with("day01"));
+        assert!(!mine.to_string_lossy().contains("53551on"));
+
+        assert_ne!(mine, path("http://localhost/2020", "07h3r"));
+        assert_ne!(mine, path("http://localhost:8080/2020", "53551on"));
+
+        // a trailing slash is the same server
+        assert_eq!(mine, path("http://localhost/2020/", "53551on"));
+
+        assert_eq!(fnv1a(&[b"a"]), 0xaf63_dc4c_8601_ec8c);
+    }
+
+    #[test]
+    fn unsupported_url() {
+        let fetcher = fetcher("ftp://example.com".to_owned(), "url");
+        assert!(fetcher.fetch(1).unwrap_err().starts_with("unsupported url"));
+    }
+}
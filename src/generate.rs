@@ -0,0 +1,561 @@
+// Synthetic puzzle inputs for each day, generated from a seed so that they can
+// be reproduced, and built so that both parts always have an answer. Sizes are
+// bounded where the answers would otherwise overflow, and where the slower
+// solvers would take too long to solve the largest size.
+use std::collections::HashSet;
+use std::ops::RangeInclusive;
+
+use crate::day09::PREAMBLE_SIZE;
+use crate::day11::{Seat, Seats};
+use crate::rng::Rng;
+
+pub struct Generator {
+    pub day: u32,
+    // what the size counts, e.g. "numbers"
+    pub unit: &'static str,
+    // about the size of the bundled input
+    pub default_size: usize,
+    pub sizes: RangeInclusive<usize>,
+    pub generate: fn(usize, &mut Rng) -> String
+}
+
+pub const GENERATORS: &[Generator] = &[
+    Generator { day: 1, unit: "numbers", default_size: 200, sizes: 5..=2_000, generate: expense_report },
+    Generator { day: 2, unit: "passwords", default_size: 1000, sizes: 1..=100_000, generate: passwords },
+    Generator { day: 3, unit: "rows", default_size: 323, sizes: 1..=10_000, generate: tree_map },
+    Generator { day: 4, unit: "passports", default_size: 290, sizes: 1..=100_000, generate: passports },
+    Generator { day: 5, unit: "boarding passes", default_size: 800, sizes: 2..=1023, generate: boarding_passes },
+    Generator { day: 6, unit: "groups", default_size: 490, sizes: 1..=20_000, generate: customs_answers },
+    Generator { day: 7, unit: "rules", default_size: 594, sizes: 3..=1024, generate: bag_rules },
+    Generator { day: 8, unit: "instructions", default_size: 600, sizes: 2..=10_000, generate: boot_code },
+    Generator { day: 9, unit: "numbers", default_size: 1000, sizes: (PREAMBLE_SIZE + 2)..=1000, generate: xmas_data },
+    Generator { day: 10, unit: "adapters", default_size: 100, sizes: 1..=1_000_000, generate: adapters },
+    Generator { day: 11, unit: "rows and columns", default_size: 90, sizes: 1..=100, generate: seat_layout },
+    Generator { day: 12, unit: "instructions", default_size: 780, sizes: 1..=10_000, generate: navigation },
+    Generator { day: 13, unit: "bus slots", default_size: 60, sizes: 1..=10_000, generate: bus_notes },
+];
+
+pub fn generator(day: u32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|g| g.day == day)
+}
+
+// the default size is used if size is None
+pub fn generate(day: u32, size: Option<usize>, seed: u64) -> Result<String, String> {
+    let generator = generator(day).ok_or_else(|| format!("no generator for day {}", day))?;
+    let size = size.unwrap_or(generator.default_size);
+
+    if !generator.sizes.contains(&size) {
+        return Err(format!(
+            "invalid size for day {}: {}, must be from {} to {} {}",
+            day, size, generator.sizes.start(), generator.sizes.end(), generator.unit
+        ));
+    }
+
+    Ok((generator.generate)(size, &mut Rng::new(seed)))
+}
+
+fn lines(lines: Vec<String>) -> String {
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+// a pair and a triple summing to 2020, and no other pair that does
+fn expense_report(size: usize, rng: &mut Rng) -> String {
+    const TARGET: i64 = crate::day01::TARGET as i64;
+
+    let mut numbers = vec![];
+    let mut seen = HashSet::new();
+
+    let first = rng.range(1..(TARGET / 2));
+    numbers.extend(&[first, TARGET - first]);
+
+    let triple = loop {
+        let x = rng.range(1..(TARGET - 1));
+        let y = rng.range(1..(TARGET - x));
+        let triple = [x, y, TARGET - x - y];
+
+        let distinct = x != y && y != triple[2] && x != triple[2];
+        let pairs_up = triple.iter().any(|&n| numbers.contains(&n) || numbers.contains(&(TARGET - n)));
+
+        if distinct && !pairs_up {
+            break triple;
+        }
+    };
+
+    numbers.extend(&triple);
+    seen.extend(numbers.iter().copied());
+
+    let max = (size as i64 * 4).max(2 * TARGET);
+
+    while numbers.len() < size {
+        let n = rng.range(1..(max + 1));
+
+        if !seen.contains(&n) && !seen.contains(&(TARGET - n)) {
+            numbers.push(n);
+            seen.insert(n);
+        }
+    }
+
+    rng.shuffle(&mut numbers);
+
+    lines(numbers.iter().map(|n| n.to_string()).collect())
+}
+
+fn letter(rng: &mut Rng) -> char {
+    (b'a' + rng.below(26) as u8) as char
+}
+
+fn passwords(size: usize, rng: &mut Rng) -> String {
+    lines((0..size).map(|_| {
+        let first = rng.range(1..8);
+        let second = rng.range((first + 1)..(first + 10));
+        let ch = letter(rng);
+
+        let password = (0..rng.range(1..21))
+            .map(|_| if rng.chance(1, 3) { ch } else { letter(rng) })
+            .collect::<String>();
+
+        format!("{}-{} {}: {}", first, second, ch, password)
+    }).collect())
+}
+
+fn tree_map(size: usize, rng: &mut Rng) -> String {
+    const WIDTH: usize = 31;
+
+    lines((0..size).map(|_| {
+        (0..WIDTH).map(|_| if rng.chance(1, 4) { '#' } else { '.' }).collect()
+    }).collect())
+}
+
+// about half of the passports are valid; the rest are missing a field or have
+// one invalid value
+fn passports(size: usize, rng: &mut Rng) -> String {
+    const EYE_COLORS: &[&str] = &["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+    let passports = (0..size).map(|_| {
+        let hex = |rng: &mut Rng| (0..6).map(|_| *rng.choose(b"0123456789abcdef") as char).collect::<String>();
+        let digits = |rng: &mut Rng, n: usize| (0..n).map(|_| (b'0' + rng.below(10) as u8) as char).collect::<String>();
+
+        let mut fields = vec![
+            ("byr", rng.range(1920..2003).to_string()),
+            ("iyr", rng.range(2010..2021).to_string()),
+            ("eyr", rng.range(2020..2031).to_string()),
+            ("hgt", if rng.chance(1, 2) {
+                format!("{}cm", rng.range(150..194))
+            } else {
+                format!("{}in", rng.range(59..77))
+            }),
+            ("hcl", format!("#{}", hex(rng))),
+            ("ecl", rng.choose(EYE_COLORS).to_string()),
+            ("pid", digits(rng, 9))
+        ];
+
+        let broken = rng.index(fields.len());
+
+        match rng.below(3) {
+            0 => {}
+            1 => { fields.remove(broken); }
+            _ => fields[broken].1 = match fields[broken].0 {
+                "byr" => rng.range(1900..1920).to_string(),
+                "iyr" => rng.range(2000..2010).to_string(),
+                "eyr" => rng.range(2031..2040).to_string(),
+                "hgt" => rng.range(150..194).to_string(),
+                "hcl" => hex(rng),
+                "ecl" => "wat".to_owned(),
+                _ => digits(rng, 10)
+            }
+        }
+
+        if rng.chance(1, 2) {
+            fields.push(("cid", rng.range(100..351).to_string()));
+        }
+
+        rng.shuffle(&mut fields);
+
+        fields.iter().enumerate()
+            .map(|(index, (key, value))| {
+                let separator = match index {
+                    0 => "",
+                    _ if rng.chance(1, 3) => "\n",
+                    _ => " "
+                };
+                format!("{}{}:{}", separator, key, value)
+            })
+            .collect::<String>()
+    }).collect::<Vec<_>>();
+
+    passports.join("\n\n") + "\n"
+}
+
+// consecutive seats, with exactly one missing in between
+fn boarding_passes(size: usize, rng: &mut Rng) -> String {
+    let count = size as i64 + 1;
+    let start = rng.range(0..(1024 - count + 1));
+    let missing = start + rng.range(1..(count - 1));
+
+    let mut ids = (start..(start + count)).filter(|&id| id != missing).collect::<Vec<_>>();
+    rng.shuffle(&mut ids);
+
+    lines(ids.iter().map(|&id| {
+        let row = (0..7).rev().map(|bit| if (id >> 3) & (1 << bit) != 0 { 'B' } else { 'F' });
+        let col = (0..3).rev().map(|bit| if id & (1 << bit) != 0 { 'R' } else { 'L' });
+        row.chain(col).collect()
+    }).collect())
+}
+
+fn customs_answers(size: usize, rng: &mut Rng) -> String {
+    let groups = (0..size).map(|_| {
+        let members = (0..rng.range(1..6)).map(|_| {
+            let mut answers = (b'a'..=b'z').filter(|_| rng.chance(1, 3)).map(char::from).collect::<String>();
+
+            if answers.is_empty() {
+                answers.push(letter(rng));
+            }
+
+            answers
+        });
+
+        members.collect::<Vec<_>>().join("\n")
+    }).collect::<Vec<_>>();
+
+    groups.join("\n\n") + "\n"
+}
+
+// rules forming a directed acyclic graph, where shiny gold bags are both
+// contained in and contain other bags; no bag holds more than a trillion
+fn bag_rules(size: usize, rng: &mut Rng) -> String {
+    const ADJECTIVES: &[&str] = &[
+        "bright", "clear", "dark", "dim", "dotted", "drab", "dull", "faded", "light", "mirrored",
+        "muted", "pale", "plaid", "posh", "shiny", "striped", "vibrant", "wavy", "dashed", "dusty",
+        "mild", "vivid", "bronze", "clean", "matte", "glossy", "hazy", "soft", "deep", "rich", "warm",
+        "cool"
+    ];
+    const HUES: &[&str] = &[
+        "aqua", "beige", "black", "blue", "bronze", "brown", "chartreuse", "coral", "crimson",
+        "cyan", "fuchsia", "gold", "gray", "green", "indigo", "lavender", "lime", "magenta",
+        "maroon", "olive", "orange", "plum", "purple", "red", "salmon", "silver", "tan", "teal",
+        "tomato", "turquoise", "violet", "white"
+    ];
+    const MAX_BAGS: u64 = 1_000_000_000_000;
+
+    let mut colors = ADJECTIVES.iter()
+        .flat_map(|adjective| HUES.iter().map(move |hue| format!("{} {}", adjective, hue)))
+        .filter(|color| color != crate::day07::TARGET_COLOR)
+        .collect::<Vec<_>>();
+
+    rng.shuffle(&mut colors);
+    colors.truncate(size - 1);
+
+    // bags may only contain bags after them, so the target is somewhere in the
+    // middle, with one bag chosen to contain it
+    let target = size / 2;
+    colors.insert(target, crate::day07::TARGET_COLOR.to_owned());
+    let target_parent = rng.index(target);
+
+    // the number of bags inside each bag, and what it contains
+    let mut totals = vec![0u64; size];
+    let mut contents = vec![vec![]; size];
+
+    for i in (0..size).rev() {
+        let mut candidates = ((i + 1)..size).collect::<Vec<_>>();
+        rng.shuffle(&mut candidates);
+
+        let mut wanted = if rng.chance(1, 5) { 0 } else { rng.index(5) };
+
+        if i == target_parent {
+            candidates.retain(|&j| j != target);
+            candidates.insert(0, target);
+            wanted = wanted.max(1);
+        } else if i == target {
+            wanted = wanted.max(1);
+        }
+
+        for j in candidates.into_iter().take(wanted) {
+            let count = if i == target_parent && j == target { 1 } else { rng.range(1..6) as u64 };
+            let total = totals[i] + count * (1 + totals[j]);
+
+            if total <= MAX_BAGS || (i == target_parent && j == target) {
+                totals[i] = total;
+                contents[i].push((count, j));
+            }
+        }
+    }
+
+    let mut rules = (0..size).map(|i| {
+        let contains = if contents[i].is_empty() {
+            "no other bags".to_owned()
+        } else {
+            contents[i].iter()
+                .map(|&(count, j)| format!("{} {} bag{}", count, colors[j], if count == 1 { "" } else { "s" }))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        format!("{} bags contain {}.", colors[i], contains)
+    }).collect::<Vec<_>>();
+
+    rng.shuffle(&mut rules);
+
+    lines(rules)
+}
+
+// a program which runs forwards to its end, except for one jmp which goes back
+// and loops. Flipping that jmp is the only repair: no jump before it passes
+// over it, so flipping anything else still leads back to it.
+fn boot_code(size: usize, rng: &mut Rng) -> String {
+    let corrupted = size / 2 + rng.index(size - size / 2);
+    let loop_start = rng.index(corrupted);
+
+    lines((0..size).map(|i| {
+        if i == corrupted {
+            return format!("jmp {:+}", loop_start as i64 - i as i64);
+        }
+
+        // the furthest any jump may go
+        let limit = if i < corrupted { corrupted } else { size } as i64;
+        let i = i as i64;
+
+        match rng.below(20) {
+            0..=8 => format!("acc {:+}", rng.range(-50..51)),
+            9..=12 => format!("nop {:+}", rng.range(0..(limit + 1)) - i),
+            _ => format!("jmp {:+}", rng.range((i + 1)..(limit + 1)) - i)
+        }
+    }).collect())
+}
+
+// each number is the sum of two of the small numbers before it, except for one
+// which is instead the sum of a contiguous run of earlier numbers. Picking
+// small numbers keeps them from overflowing for the sizes allowed.
+fn xmas_data(size: usize, rng: &mut Rng) -> String {
+    let mut numbers = (1..=100).collect::<Vec<i64>>();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(PREAMBLE_SIZE);
+
+    let invalid_from = (size / 2).max(PREAMBLE_SIZE + 1);
+    let mut invalid = None;
+
+    for i in PREAMBLE_SIZE..size {
+        let mut window = numbers[(i - PREAMBLE_SIZE)..i].to_vec();
+        window.sort_unstable();
+
+        let is_sum_of_two = |n: i64| window.iter().enumerate()
+            .any(|(a, x)| window[(a + 1)..].iter().any(|y| x + y == n));
+
+        if invalid.is_none() && i >= invalid_from {
+            let run = (0..100).find_map(|_| {
+                let start = rng.index(i - 1);
+                let end = start + 2 + rng.index((i - start - 1).min(16));
+                let sum = numbers[start..end].iter().sum::<i64>();
+
+                if is_sum_of_two(sum) { None } else { Some(sum) }
+            });
+
+            if let Some(sum) = run {
+                invalid = Some(i);
+                numbers.push(sum);
+                continue;
+            }
+        }
+
+        let smallest = &window[..10];
+        let (a, b) = loop {
+            let (a, b) = (*rng.choose(smallest), *rng.choose(smallest));
+
+            if a != b {
+                break (a, b);
+            }
+        };
+
+        numbers.push(a + b);
+    }
+
+    lines(numbers.iter().map(|n| n.to_string()).collect())
+}
+
+// runs of up to four adapters one jolt apart, separated by gaps of three. The
+// number of arrangements is the product of the ways to arrange each run, which
+// is kept below 10^15 by using shorter runs once it gets large.
+fn adapters(size: usize, rng: &mut Rng) -> String {
+    const WAYS: [u64; 5] = [1, 1, 2, 4, 7];
+    const MAX_ARRANGEMENTS: u64 = 1_000_000_000_000_000;
+
+    let mut joltages = vec![];
+    let mut joltage = 0;
+    let mut arrangements = 1;
+
+    while joltages.len() < size {
+        let mut run = rng.index(WAYS.len());
+
+        if arrangements * WAYS[run] > MAX_ARRANGEMENTS {
+            run = 0;
+        }
+
+        arrangements *= WAYS[run];
+
+        for _ in 0..run {
+            joltage += 1;
+            joltages.push(joltage);
+        }
+
+        joltage += 3;
+        joltages.push(joltage);
+    }
+
+    joltages.truncate(size);
+    rng.shuffle(&mut joltages);
+
+    lines(joltages.iter().map(|j| j.to_string()).collect())
+}
+
+// under the part 1 rules, some layouts end up flipping between two states
+// forever, so layouts are drawn until one settles under the rules of both parts
+fn seat_layout(size: usize, rng: &mut Rng) -> String {
+    loop {
+        let layout = lines((0..size).map(|_| {
+            (0..size).map(|_| if rng.chance(3, 4) { 'L' } else { '.' }).collect()
+        }).collect());
+
+        if let Ok(seats) = crate::day11::parse(&layout) {
+            let settles_in = |iterate_seat| settles(&seats, iterate_seat);
+
+            if settles_in(crate::day11::part1::iterate_seat) && settles_in(crate::day11::part2::iterate_seat) {
+                return layout;
+            }
+        }
+    }
+}
+
+fn settles(seats: &Seats, iterate_seat: fn(&Seats, usize, usize) -> Seat) -> bool {
+    let mut previous = None;
+    let mut current = seats.clone();
+
+    // layouts which do settle take far fewer rounds than this
+    for _ in 0..(10 * seats.seats.height() + 100) {
+        let next = current.iterate(iterate_seat);
+
+        if next == current {
+            return true;
+        } else if previous.as_ref() == Some(&next) {
+            return false;
+        }
+
+        previous = Some(current);
+        current = next;
+    }
+
+    false
+}
+
+fn navigation(size: usize, rng: &mut Rng) -> String {
+    lines((0..size).map(|_| {
+        let action = *rng.choose(b"NSEWLRFFF") as char;
+
+        let value = match action {
+            'L' | 'R' => *rng.choose(&[90, 180, 270]),
+            _ => rng.range(1..101)
+        };
+
+        format!("{}{}", action, value)
+    }).collect())
+}
+
+// bus ids are distinct primes, so that they are coprime, and their product is
+// kept below 10^14 so that the earliest timestamp fits
+fn bus_notes(size: usize, rng: &mut Rng) -> String {
+    const MAX_PRODUCT: i64 = 100_000_000_000_000;
+
+    let primes = (11..1000)
+        .filter(|&n: &i64| (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0))
+        .collect::<Vec<_>>();
+
+    let mut slots = vec!["x".to_owned(); size];
+    let mut positions = (1..size).collect::<Vec<_>>();
+    rng.shuffle(&mut positions);
+    positions.insert(0, 0);
+
+    let mut used = HashSet::new();
+    let mut product = 1;
+
+    for position in positions.into_iter().take((size / 6).max(1)) {
+        let id = *rng.choose(&primes);
+
+        if used.contains(&id) || product * id > MAX_PRODUCT {
+            continue;
+        }
+
+        used.insert(id);
+        product *= id;
+        slots[position] = id.to_string();
+    }
+
+    format!("{}\n{}\n", rng.range(100_000..1_000_001), slots.join(","))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::SOLVERS;
+
+    #[test]
+    fn reproducible() {
+        for generator in GENERATORS {
+            let size = Some(small_size(generator));
+
+            assert_eq!(generate(generator.day, size, 42), generate(generator.day, size, 42));
+            assert_ne!(generate(generator.day, size, 42), generate(generator.day, size, 43));
+        }
+    }
+
+    // big enough to exercise each generator, small enough to solve quickly
+    fn small_size(generator: &Generator) -> usize {
+        10.clamp(*generator.sizes.start(), *generator.sizes.end())
+    }
+
+    fn assert_every_part_answers(generator: &Generator, size: usize, seed: u64) {
+        let input = generate(generator.day, Some(size), seed).unwrap();
+
+        for solver in SOLVERS.iter().filter(|s| s.day == generator.day) {
+            let run = (solver.run)(&input).unwrap_or_else(|e| {
+                panic!("day {} size {} seed {}: {}", generator.day, size, seed, e)
+            });
+
+            assert!(run.answer.is_some(), "{} has no answer for size {} seed {}", solver.label(), size, seed);
+        }
+    }
+
+    #[test]
+    fn every_part_has_an_answer() {
+        for generator in GENERATORS {
+            assert_every_part_answers(generator, *generator.sizes.start(), 0);
+            assert_every_part_answers(generator, small_size(generator), 1);
+        }
+    }
+
+    // slow in a debug build: cargo test --release -- --ignored
+    #[test]
+    #[ignore]
+    fn every_part_has_an_answer_at_the_largest_size() {
+        for generator in GENERATORS {
+            assert_every_part_answers(generator, *generator.sizes.end(), 2);
+        }
+    }
+
+    #[test]
+    fn known_answers() {
+        // the seat left out, and the corrupted instruction
+        let input = generate(5, Some(2), 1).unwrap();
+        let seats = crate::day05::parse(&input).unwrap();
+        let ids = seats.iter().map(|&(row, col)| crate::day05::seat_id(row, col)).collect::<Vec<_>>();
+        assert_eq!(crate::day05::part2::solve(&seats), Some(ids.iter().min().unwrap() + 1));
+
+        let input = generate(8, Some(2), 1).unwrap();
+        assert_eq!(input.lines().nth(1), Some("jmp -1"));
+        assert_eq!(crate::day08::part2::find_repair(&crate::day08::parse(&input).unwrap()).map(|(i, _)| i), Some(1));
+    }
+
+    #[test]
+    fn rejects_bad_sizes() {
+        assert!(generate(5, Some(1024), 0).is_err());
+        assert!(generate(1, Some(4), 0).is_err());
+        assert!(generate(26, None, 0).is_err());
+    }
+}
@@ -0,0 +1,219 @@
+// Splitting text into extended grapheme clusters, what a reader sees as single
+// characters: a letter with its combining accents, a Hangul syllable spelled
+// out in jamo, a flag made of two regional indicators, or emoji joined by zero
+// width joiners. This follows the rules of Unicode Standard Annex #29, with a
+// table of the character classes covering the common scripts and emoji
+// rather than all of Unicode.
+use self::Class::*;
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Class {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    // Hangul jamo: leading consonants, vowels and trailing consonants, and the
+    // precomposed syllables without and with a trailing consonant
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+    // Extended_Pictographic, which isn't a class of its own in the annex but
+    // never overlaps the others
+    Pictographic
+}
+
+// the classes of ranges of characters, in order; anything else is Other
+const CLASSES: &[(u32, u32, Class)] = &[
+    (0x0000, 0x0009, Control), (0x000A, 0x000A, Lf), (0x000B, 0x000C, Control), (0x000D, 0x000D, Cr),
+    (0x000E, 0x001F, Control), (0x007F, 0x009F, Control), (0x00A9, 0x00A9, Pictographic), (0x00AD, 0x00AD, Control),
+    (0x00AE, 0x00AE, Pictographic), (0x0300, 0x036F, Extend), (0x0483, 0x0489, Extend), (0x0591, 0x05BD, Extend),
+    (0x05BF, 0x05BF, Extend), (0x05C1, 0x05C2, Extend), (0x05C4, 0x05C5, Extend), (0x05C7, 0x05C7, Extend),
+    (0x0600, 0x0605, Prepend), (0x0610, 0x061A, Extend), (0x061C, 0x061C, Control), (0x064B, 0x065F, Extend),
+    (0x0670, 0x0670, Extend), (0x06D6, 0x06DC, Extend), (0x06DD, 0x06DD, Prepend), (0x06DF, 0x06E4, Extend),
+    (0x06E7, 0x06E8, Extend), (0x06EA, 0x06ED, Extend), (0x070F, 0x070F, Prepend), (0x0711, 0x0711, Extend),
+    (0x0730, 0x074A, Extend), (0x0900, 0x0902, Extend), (0x0903, 0x0903, SpacingMark), (0x093A, 0x093A, Extend),
+    (0x093B, 0x093B, SpacingMark), (0x093C, 0x093C, Extend), (0x093E, 0x0940, SpacingMark), (0x0941, 0x0948, Extend),
+    (0x0949, 0x094C, SpacingMark), (0x094D, 0x094D, Extend), (0x094E, 0x094F, SpacingMark), (0x0951, 0x0957, Extend),
+    (0x0962, 0x0963, Extend), (0x0981, 0x0981, Extend), (0x0982, 0x0983, SpacingMark), (0x09BC, 0x09BC, Extend),
+    (0x09BE, 0x09BE, Extend), (0x09BF, 0x09C0, SpacingMark), (0x09C1, 0x09C4, Extend), (0x09C7, 0x09C8, SpacingMark),
+    (0x09CB, 0x09CC, SpacingMark), (0x09CD, 0x09CD, Extend), (0x09D7, 0x09D7, Extend), (0x09E2, 0x09E3, Extend),
+    (0x0E31, 0x0E31, Extend), (0x0E33, 0x0E33, SpacingMark), (0x0E34, 0x0E3A, Extend), (0x0E47, 0x0E4E, Extend),
+    (0x0EB1, 0x0EB1, Extend), (0x0EB3, 0x0EB3, SpacingMark), (0x0EB4, 0x0EBC, Extend), (0x0EC8, 0x0ECE, Extend),
+    (0x1100, 0x115F, L), (0x1160, 0x11A7, V), (0x11A8, 0x11FF, T), (0x180E, 0x180E, Control),
+    (0x1AB0, 0x1AFF, Extend), (0x1DC0, 0x1DFF, Extend), (0x200B, 0x200B, Control), (0x200C, 0x200C, Extend),
+    (0x200D, 0x200D, Zwj), (0x200E, 0x200F, Control), (0x2028, 0x202E, Control), (0x203C, 0x203C, Pictographic),
+    (0x2049, 0x2049, Pictographic), (0x2060, 0x206F, Control), (0x20D0, 0x20F0, Extend), (0x2122, 0x2122, Pictographic),
+    (0x2139, 0x2139, Pictographic), (0x2194, 0x2199, Pictographic), (0x21A9, 0x21AA, Pictographic), (0x231A, 0x231B, Pictographic),
+    (0x2328, 0x2328, Pictographic), (0x23CF, 0x23CF, Pictographic), (0x23E9, 0x23F3, Pictographic), (0x23F8, 0x23FA, Pictographic),
+    (0x24C2, 0x24C2, Pictographic), (0x25AA, 0x25AB, Pictographic), (0x25B6, 0x25B6, Pictographic), (0x25C0, 0x25C0, Pictographic),
+    (0x25FB, 0x25FE, Pictographic), (0x2600, 0x27BF, Pictographic), (0x2934, 0x2935, Pictographic), (0x2B05, 0x2B07, Pictographic),
+    (0x2B1B, 0x2B1C, Pictographic), (0x2B50, 0x2B50, Pictographic), (0x2B55, 0x2B55, Pictographic), (0x302A, 0x302F, Extend),
+    (0x3030, 0x3030, Pictographic), (0x303D, 0x303D, Pictographic), (0x3099, 0x309A, Extend), (0x3297, 0x3297, Pictographic),
+    (0x3299, 0x3299, Pictographic), (0xA960, 0xA97C, L), (0xD7B0, 0xD7C6, V), (0xD7CB, 0xD7FB, T),
+    (0xFE00, 0xFE0F, Extend), (0xFE20, 0xFE2F, Extend), (0xFEFF, 0xFEFF, Control), (0xFF9E, 0xFF9F, Extend),
+    (0xFFF0, 0xFFFB, Control), (0x1F000, 0x1F0FF, Pictographic), (0x1F10D, 0x1F10F, Pictographic), (0x1F12F, 0x1F12F, Pictographic),
+    (0x1F16C, 0x1F171, Pictographic), (0x1F17E, 0x1F17F, Pictographic), (0x1F18E, 0x1F18E, Pictographic), (0x1F191, 0x1F19A, Pictographic),
+    (0x1F1AD, 0x1F1E5, Pictographic), (0x1F1E6, 0x1F1FF, RegionalIndicator), (0x1F201, 0x1F20F, Pictographic), (0x1F21A, 0x1F21A, Pictographic),
+    (0x1F22F, 0x1F22F, Pictographic), (0x1F232, 0x1F23A, Pictographic), (0x1F23C, 0x1F23F, Pictographic), (0x1F249, 0x1F3FA, Pictographic),
+    (0x1F3FB, 0x1F3FF, Extend), (0x1F400, 0x1F53D, Pictographic), (0x1F546, 0x1F64F, Pictographic), (0x1F680, 0x1F6FF, Pictographic),
+    (0x1F774, 0x1F77F, Pictographic), (0x1F7D5, 0x1F7FF, Pictographic), (0x1F80C, 0x1F80F, Pictographic), (0x1F848, 0x1F84F, Pictographic),
+    (0x1F85A, 0x1F85F, Pictographic), (0x1F888, 0x1F88F, Pictographic), (0x1F8AE, 0x1F8FF, Pictographic), (0x1F90C, 0x1F93A, Pictographic),
+    (0x1F93C, 0x1F945, Pictographic), (0x1F947, 0x1FAFF, Pictographic), (0x1FC00, 0x1FFFD, Pictographic), (0xE0000, 0xE001F, Control),
+    (0xE0020, 0xE007F, Extend), (0xE0080, 0xE00FF, Control), (0xE0100, 0xE01EF, Extend), (0xE01F0, 0xE0FFF, Control)
+];
+
+// the precomposed Hangul syllables, each a leading consonant and a vowel,
+// followed by one of 27 trailing consonants or none
+const HANGUL_SYLLABLES: (u32, u32) = (0xAC00, 0xD7A3);
+const HANGUL_TRAILING: u32 = 28;
+
+fn class(c: char) -> Class {
+    let code = c as u32;
+
+    if c.is_ascii() && !c.is_ascii_control() {
+        return Other;
+    }
+
+    if (HANGUL_SYLLABLES.0..=HANGUL_SYLLABLES.1).contains(&code) {
+        return if (code - HANGUL_SYLLABLES.0) % HANGUL_TRAILING == 0 { Lv } else { Lvt };
+    }
+
+    let index = CLASSES.partition_point(|&(_, last, _)| last < code);
+
+    match CLASSES.get(index) {
+        Some(&(first, _, class)) if first <= code => class,
+        _ => Other
+    }
+}
+
+// the grapheme clusters of s, in order
+pub fn graphemes(s: &str) -> Graphemes<'_> {
+    Graphemes { rest: s }
+}
+
+pub struct Graphemes<'a> {
+    rest: &'a str
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+        let mut cluster = Cluster::new(class(first));
+
+        let end = chars.find(|&(_, c)| !cluster.continues(class(c)))
+            .map_or(self.rest.len(), |(index, _)| index);
+
+        let (grapheme, rest) = self.rest.split_at(end);
+        self.rest = rest;
+
+        Some(grapheme)
+    }
+}
+
+// what decides whether a cluster continues with the next character
+struct Cluster {
+    last: Class,
+    // whether the cluster ends in a pictograph and any extending characters,
+    // or in those and a zero width joiner, which a pictograph continues
+    pictograph: bool,
+    joined_pictograph: bool,
+    // the regional indicators the cluster ends with, which pair up into flags
+    regional_indicators: usize
+}
+
+impl Cluster {
+    fn new(first: Class) -> Self {
+        Self {
+            last: first,
+            pictograph: first == Pictographic,
+            joined_pictograph: false,
+            regional_indicators: (first == RegionalIndicator) as usize
+        }
+    }
+
+    // whether a character of class next continues the cluster, which then
+    // includes it
+    fn continues(&mut self, next: Class) -> bool {
+        // the rules of the annex in order, GB3 to GB13
+        let continues = match (self.last, next) {
+            (Cr, Lf) => true,
+            (Control | Cr | Lf, _) | (_, Control | Cr | Lf) => false,
+            (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => true,
+            (_, Extend | Zwj | SpacingMark) | (Prepend, _) => true,
+            (Zwj, Pictographic) => self.joined_pictograph,
+            (RegionalIndicator, RegionalIndicator) => self.regional_indicators % 2 == 1,
+            _ => false
+        };
+
+        if continues {
+            self.joined_pictograph = next == Zwj && self.pictograph;
+            self.pictograph = next == Pictographic || (self.pictograph && next == Extend);
+            self.regional_indicators = if next == RegionalIndicator { self.regional_indicators + 1 } else { 0 };
+            self.last = next;
+        }
+
+        continues
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(s: &str) -> Vec<&str> {
+        graphemes(s).collect()
+    }
+
+    #[test]
+    fn clusters() {
+        assert_eq!(split(""), Vec::<&str>::new());
+        assert_eq!(split("abc"), vec!["a", "b", "c"]);
+        assert_eq!(split("a\r\nb\n\r"), vec!["a", "\r\n", "b", "\n", "\r"]);
+
+        // combining accents, and the precomposed letter they look the same as
+        assert_eq!(split("cafe\u{301}s"), vec!["c", "a", "f", "e\u{301}", "s"]);
+        assert_eq!(split("caf\u{e9}"), vec!["c", "a", "f", "\u{e9}"]);
+        assert_eq!(split("a\u{308}\u{323}!"), vec!["a\u{308}\u{323}", "!"]);
+
+        // Hangul syllables, precomposed and spelled out in jamo
+        assert_eq!(split("\u{d55c}\u{1112}\u{1161}\u{11ab}\u{1100}"), vec!["\u{d55c}", "\u{1112}\u{1161}\u{11ab}", "\u{1100}"]);
+
+        // Devanagari with a spacing mark
+        assert_eq!(split("\u{915}\u{93f}\u{928}"), vec!["\u{915}\u{93f}", "\u{928}"]);
+
+        // flags pair up their regional indicators
+        assert_eq!(split("\u{1f1eb}\u{1f1f7}\u{1f1e9}\u{1f1ea}\u{1f1ee}"), vec!["\u{1f1eb}\u{1f1f7}", "\u{1f1e9}\u{1f1ea}", "\u{1f1ee}"]);
+
+        // emoji with a skin tone, and joined into one
+        assert_eq!(split("\u{1f44d}\u{1f3fd}x"), vec!["\u{1f44d}\u{1f3fd}", "x"]);
+        assert_eq!(split("\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}"), vec!["\u{1f469}\u{200d}\u{1f469}\u{200d}\u{1f467}"]);
+        assert_eq!(split("\u{2764}\u{fe0f}\u{200d}\u{1f525}"), vec!["\u{2764}\u{fe0f}\u{200d}\u{1f525}"]);
+
+        // a joiner only joins pictographs
+        assert_eq!(split("a\u{200d}b"), vec!["a\u{200d}", "b"]);
+    }
+
+    #[test]
+    fn ascii_is_one_char_each() {
+        let ascii = (0..128u8).map(char::from).filter(|&c| c != '\r').collect::<String>();
+
+        assert_eq!(split(&ascii), ascii.char_indices().map(|(i, c)| &ascii[i..(i + c.len_utf8())]).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn classes_are_ordered() {
+        for pair in CLASSES.windows(2) {
+            assert!(pair[0].0 <= pair[0].1 && pair[0].1 < pair[1].0, "{:x?}", pair);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+// A rectangular grid of cells stored row by row in a single Vec, for the days
+// whose input is a character map. Cells are looked up by (row, col), either
+// checked against the edges or wrapping around them, and the cells around one
+// are found by stepping in a direction: once for its neighbours, or on until
+// the edge for what can be seen from it.
+use std::convert::TryFrom;
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{parse_grid, ParseError};
+
+// (rows down, columns right)
+pub type Direction = (isize, isize);
+
+// the eight cells around one, diagonals included, row by row
+pub const ADJACENT: &[Direction] = &[
+    (-1, -1), (-1,  0), (-1,  1),
+    ( 0, -1),           ( 0,  1),
+    ( 1, -1), ( 1,  0), ( 1,  1)
+];
+
+// up, left, right and down
+pub const ORTHOGONAL: &[Direction] = &[(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize
+}
+
+impl<T> Grid<T> {
+    // every cell set to fill
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where T: Clone {
+        Self { cells: vec![fill; width * height], width, height }
+    }
+
+    // every cell set to what cell returns for its (row, col)
+    pub fn from_fn<F>(width: usize, height: usize, mut cell: F) -> Self
+    where F: FnMut(usize, usize) -> T {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| cell(row, col))
+            .collect();
+
+        Self { cells, width, height }
+    }
+
+    // one cell per character, converted by the TryFrom<char> of the cell
+    pub fn parse(input: &str, expected: &str) -> Result<Self, ParseError>
+    where T: TryFrom<char> {
+        Self::parse_with(input, expected, |c| T::try_from(c).ok())
+    }
+
+    // one cell per character, converted by parse_cell
+    pub fn parse_with<F>(input: &str, expected: &str, parse_cell: F) -> Result<Self, ParseError>
+    where F: FnMut(char) -> Option<T> {
+        let rows = parse_grid(input, expected, parse_cell)?;
+
+        // parse_grid has checked that every row is the same width
+        let width = rows.first().map_or(0, Vec::len);
+        let height = rows.len();
+
+        Ok(Self { cells: rows.into_iter().flatten().collect(), width, height })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.index_of(row, col).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.index_of(row, col).map(move |index| &mut self.cells[index])
+    }
+
+    // as if the grid were repeated in every direction. Panics if it's empty
+    pub fn get_wrapping(&self, row: usize, col: usize) -> &T {
+        &self[(row % self.height, col % self.width)]
+    }
+
+    // the position one step from (row, col) in direction, if it's in the grid
+    pub fn step(&self, (row, col): (usize, usize), (d_row, d_col): Direction) -> Option<(usize, usize)> {
+        let row = row.checked_add_signed(d_row)?;
+        let col = col.checked_add_signed(d_col)?;
+
+        self.index_of(row, col).map(|_| (row, col))
+    }
+
+    // the cells one step from (row, col) in each of directions, leaving out
+    // those past the edge
+    pub fn neighbours_in<'a>(&'a self, row: usize, col: usize, directions: &'a [Direction]) -> impl Iterator<Item = &'a T> + 'a {
+        directions.iter()
+            .filter_map(move |&direction| self.step((row, col), direction))
+            .map(move |position| &self[position])
+    }
+
+    // the eight cells around (row, col)
+    pub fn neighbours(&self, row: usize, col: usize) -> impl Iterator<Item = &T> + '_ {
+        self.neighbours_in(row, col, ADJACENT)
+    }
+
+    // the cells from (row, col) in direction up to the edge, nearest first,
+    // not including (row, col) itself
+    pub fn ray(&self, row: usize, col: usize, direction: Direction) -> impl Iterator<Item = &T> + '_ {
+        std::iter::successors(self.step((row, col), direction), move |&position| self.step(position, direction))
+            .map(move |position| &self[position])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // chunks panics on 0, and a grid 0 wide has no cells to split anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    // every cell, row by row
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.cells.iter()
+    }
+
+    fn index_of(&self, row: usize, col: usize) -> Option<usize> {
+        if row < self.height && col < self.width {
+            Some(row * self.width + col)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        match self.index_of(row, col) {
+            Some(index) => &self.cells[index],
+            None => panic!("({}, {}) is outside a grid {} wide and {} high", row, col, self.width, self.height)
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+
+        match self.get_mut(row, col) {
+            Some(cell) => cell,
+            None => panic!("({}, {}) is outside a grid {} wide and {} high", row, col, width, height)
+        }
+    }
+}
+
+// one line per row, with each cell as its character
+impl<T: Copy> Display for Grid<T>
+where char: From<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().map(|&cell| char::from(cell)).collect::<String>())?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+abc
+def
+ghi
+jkl
+";
+
+    fn letters() -> Grid<char> {
+        Grid::parse_with(EXAMPLE, "a letter", |c| Some(c).filter(char::is_ascii_lowercase)).unwrap()
+    }
+
+    #[test]
+    fn accessors() {
+        let mut grid = letters();
+        assert_eq!((grid.width(), grid.height()), (3, 4));
+
+        assert_eq!(grid.get(1, 2), Some(&'f'));
+        assert_eq!(grid.get(4, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid[(3, 0)], 'j');
+
+        assert_eq!(grid.get_wrapping(5, 7), &'e');
+        assert_eq!(grid.get_wrapping(4, 3), &'a');
+
+        grid[(0, 0)] = 'z';
+        *grid.get_mut(3, 2).unwrap() = 'y';
+        assert_eq!(grid.to_string(), "zbc\ndef\nghi\njky\n");
+        assert!(grid.get_mut(0, 3).is_none());
+
+        let rows = grid.rows().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>();
+        assert_eq!(rows, vec!["zbc", "def", "ghi", "jky"]);
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = letters();
+        let around = |row, col| grid.neighbours(row, col).collect::<String>();
+
+        assert_eq!(around(1, 1), "abcdfghi");
+        assert_eq!(around(0, 0), "bde");
+        assert_eq!(around(3, 2), "hik");
+        assert_eq!(grid.neighbours_in(1, 0, ORTHOGONAL).collect::<String>(), "aeg");
+
+        assert_eq!(grid.step((0, 0), (-1, 0)), None);
+        assert_eq!(grid.step((0, 0), (1, 1)), Some((1, 1)));
+    }
+
+    #[test]
+    fn rays() {
+        let grid = letters();
+        let ray = |row, col, direction| grid.ray(row, col, direction).collect::<String>();
+
+        assert_eq!(ray(0, 0, (1, 0)), "dgj");
+        assert_eq!(ray(0, 0, (1, 1)), "ei");
+        assert_eq!(ray(3, 2, (-1, -1)), "hd");
+        assert_eq!(ray(1, 1, (0, 1)), "f");
+        assert_eq!(ray(1, 2, (0, 1)), "");
+        assert_eq!(ray(1, 1, (2, 1)), "l");
+    }
+
+    #[test]
+    fn construction() {
+        let grid = Grid::from_fn(3, 2, |row, col| char::from(b'0' + (row * 3 + col) as u8));
+        assert_eq!(grid.to_string(), "012\n345\n");
+        assert_eq!(grid.iter().count(), 6);
+
+        assert_eq!(Grid::new(2, 2, '.').to_string(), "..\n..\n");
+        assert_eq!(Grid::<char>::new(0, 0, '.').to_string(), "");
+
+        let error = Grid::<char>::parse_with("ab\nc\n", "a letter", Some).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a row 2 wide, found `c`");
+    }
+
+    #[test]
+    #[should_panic(expected = "(2, 0) is outside a grid 2 wide and 2 high")]
+    fn out_of_bounds() {
+        let _ = Grid::new(2, 2, '.')[(2, 0)];
+    }
+}
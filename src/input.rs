@@ -0,0 +1,55 @@
+// Puzzle input is read at runtime from a file or stdin, falling back to the
+// input bundled with each day when no path is given.
+use std::fs;
+use std::io::{self, Read};
+
+use crate::registry::SolveError;
+
+// a path of "-" reads from stdin
+pub fn read(path: Option<&str>, bundled: &str) -> io::Result<String> {
+    match path {
+        None => Ok(bundled.to_owned()),
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path)
+    }
+}
+
+// for the per-day binaries, which accept `--input <path>` or a lone `-` and
+// nothing else; exits with a message if the arguments or the file are bad
+pub fn from_args(bundled: &str) -> String {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+
+    let path = match args.iter().map(|s| s as &str).collect::<Vec<_>>()[..] {
+        [] => None,
+        ["-"] => Some("-"),
+        ["--input", path] => Some(path),
+        _ => {
+            eprintln!("usage: [--input <path> | -]");
+            std::process::exit(1);
+        }
+    };
+
+    match read(path, bundled) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("failed to read {}: {}", path.unwrap_or("input"), e);
+            std::process::exit(1);
+        }
+    }
+}
+
+// for the per-day binaries; exits showing where the input is malformed, or
+// why it has no answer
+pub fn or_exit<T, E: Into<SolveError>>(result: Result<T, E>) -> T {
+    match result {
+        Ok(value) => value,
+        Err(e) => {
+            eprintln!("{}", e.into().render());
+            std::process::exit(1);
+        }
+    }
+}
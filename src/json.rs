@@ -0,0 +1,123 @@
+// Just enough JSON to write out results, since nothing in the crate needs to
+// read it back.
+use std::fmt::{self, Display};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    // kept as written, so that integers of any size survive
+    Number(String),
+    String(String),
+    Array(Vec<Value>),
+    Object(Vec<(String, Value)>)
+}
+
+impl Value {
+    pub fn number<T: Display>(n: T) -> Self {
+        Value::Number(n.to_string())
+    }
+
+    pub fn string<T: Into<String>>(s: T) -> Self {
+        Value::String(s.into())
+    }
+
+    pub fn object<'a, I>(fields: I) -> Self
+    where I: IntoIterator<Item = (&'a str, Value)> {
+        Value::Object(fields.into_iter().map(|(k, v)| (k.to_owned(), v)).collect())
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(Value::Null)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(s: &str) -> Self {
+        Value::string(s)
+    }
+}
+
+impl From<String> for Value {
+    fn from(s: String) -> Self {
+        Value::String(s)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Null => write!(f, "null"),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::String(s) => write_string(f, s),
+            Value::Array(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", value)?;
+                }
+                write!(f, "]")
+            }
+            Value::Object(fields) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_string(f, key)?;
+                    write!(f, ":{}", value)?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+fn write_string(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\r' => write!(f, "\\r")?,
+            '\t' => write!(f, "\\t")?,
+            c if (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => write!(f, "{}", c)?
+        }
+    }
+
+    write!(f, "\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let value = Value::object(vec![
+            ("day", Value::number(8)),
+            ("variant", Value::from(None::<&str>)),
+            ("answer", Value::from("1121")),
+            ("ok", Value::Bool(true)),
+            ("list", Value::Array(vec![Value::number(1), Value::number(-2)])),
+        ]);
+
+        assert_eq!(
+            value.to_string(),
+            r#"{"day":8,"variant":null,"answer":"1121","ok":true,"list":[1,-2]}"#
+        );
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(Value::from("a\"b\\c\nd\u{1}").to_string(), r#""a\"b\\c\nd\u0001""#);
+    }
+}
@@ -0,0 +1,37 @@
+// Each day is a module exposing `part1` and `part2`, which take the raw
+// puzzle input and return the answer. The binaries under `src/bin` are thin
+// wrappers around these, run against `--input <path>` or the bundled `input`
+// of each day, and `registry` lists them all for the `aoc` runner.
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+
+pub mod answers;
+pub mod bigint;
+pub mod bench;
+pub mod fetch;
+pub mod generate;
+pub mod grapheme;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod number;
+pub mod pair_sums;
+pub mod parse;
+pub mod pool;
+pub mod property;
+pub mod registry;
+pub mod rng;
+pub mod scaffold;
+pub mod statement;
+pub mod trace;
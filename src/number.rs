@@ -0,0 +1,124 @@
+// Integers that solvers can be generic over: the primitive integers, whose
+// arithmetic is checked so that overflow is an error rather than a wrapped
+// answer, and BigInt, which never overflows.
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+use crate::bigint::BigInt;
+
+pub trait Number: Clone + Ord + Hash + Debug + Display + FromStr + From<i32> {
+    // twice as wide, so that a pair of values, or any sum of up to 2^32 of
+    // them, can't overflow it; more values than that might
+    type Wide: Clone + Ord + Hash + Debug + Add<Output = Self::Wide> + Sub<Output = Self::Wide> + From<i32>;
+
+    // e.g. "i64", for errors
+    const NAME: &'static str;
+
+    fn widen(&self) -> Self::Wide;
+
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+}
+
+macro_rules! impl_number {
+    ($($t:ty => $wide:ty),*) => {
+        $(impl Number for $t {
+            type Wide = $wide;
+
+            const NAME: &'static str = stringify!($t);
+
+            fn widen(&self) -> $wide {
+                <$wide>::from(*self)
+            }
+
+            fn checked_mul(&self, other: &Self) -> Option<Self> {
+                <$t>::checked_mul(*self, *other)
+            }
+        })*
+    };
+}
+
+// on a 64-bit target, the sum of usize::MAX values of an i64 still fits in
+// an i128
+impl_number!(i32 => i64, i64 => i128);
+
+impl Number for BigInt {
+    type Wide = BigInt;
+
+    const NAME: &'static str = "integer";
+
+    fn widen(&self) -> BigInt {
+        self.clone()
+    }
+
+    fn checked_mul(&self, other: &Self) -> Option<Self> {
+        Some(self.clone() * other.clone())
+    }
+}
+
+// an answer which doesn't fit in the solver's integer type
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Overflow {
+    // e.g. "product"
+    pub operation: &'static str,
+    pub operands: Vec<String>,
+    pub type_name: &'static str
+}
+
+impl Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operands = match self.operands.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+            _ => self.operands.join("")
+        };
+
+        write!(f, "the {} of {} overflows an {}", self.operation, operands, self.type_name)
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+pub fn checked_product<T: Number>(values: &[T]) -> Result<T, Overflow> {
+    values.iter()
+        .try_fold(T::from(1), |product, value| product.checked_mul(value))
+        .ok_or_else(|| Overflow {
+            operation: "product",
+            operands: values.iter().map(|value| value.to_string()).collect(),
+            type_name: T::NAME
+        })
+}
+
+// the sum of values, which can't overflow for up to 2^32 of them, as when
+// adding up k entries of a report
+pub fn wide_sum<'a, T: Number + 'a, I>(values: I) -> T::Wide
+where I: IntoIterator<Item = &'a T> {
+    values.into_iter().fold(T::Wide::from(0), |sum, value| sum + value.widen())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn products() {
+        assert_eq!(checked_product(&[979, 366, 675]), Ok(241861950));
+        assert_eq!(checked_product::<i64>(&[]), Ok(1));
+
+        let error = checked_product(&[1979, 1366, 1675]).unwrap_err();
+        assert_eq!(error.to_string(), "the product of 1979, 1366 and 1675 overflows an i32");
+
+        assert_eq!(checked_product(&[1979i64, 1366, 1675]), Ok(4528050950));
+        assert!(checked_product(&[i64::MAX, 2]).is_err());
+
+        let big = checked_product(&[BigInt::from(i64::MAX), BigInt::from(i64::MAX)]).unwrap();
+        assert_eq!(big.to_string(), "85070591730234615847396907784232501249");
+    }
+
+    #[test]
+    fn sums() {
+        assert_eq!(wide_sum(&[i32::MAX, i32::MAX, i32::MAX]), 3 * i64::from(i32::MAX));
+        assert_eq!(wide_sum(&[i64::MIN, i64::MIN]), 2 * i128::from(i64::MIN));
+        assert_eq!(wide_sum::<i64, _>(&[]), 0);
+    }
+}
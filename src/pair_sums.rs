@@ -0,0 +1,245 @@
+// A multiset of numbers which answers whether two of them sum to a target,
+// without keeping them sorted: each distinct value is hashed by its wide value,
+// so inserting, removing and looking up the entry that would complete a pair
+// are all constant time on average. Day 1 streams its report through one.
+//
+// Whether any two entries sum to a target takes a pass over the distinct values,
+// unless the multiset also counts the pairs having each sum, which makes that a
+// single lookup at the cost of inserting and removing in time linear in the
+// distinct values. Day 9 counts them, as it queries its window once for every
+// number slid into it and the window is small.
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use crate::number::Number;
+
+#[derive(Debug, Clone)]
+pub struct PairSums<T: Number> {
+    // each distinct value, and how many entries have it
+    entries: HashMap<T::Wide, (T, usize)>,
+    // if counted, each sum of two entries, and how many pairs have it
+    sums: Option<HashMap<T::Wide, usize>>,
+    len: usize
+}
+
+impl<T: Number> Default for PairSums<T> {
+    fn default() -> Self {
+        Self { entries: HashMap::new(), sums: None, len: 0 }
+    }
+}
+
+impl<T: Number> PairSums<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // one which also counts the pairs having each sum
+    pub fn counting_sums() -> Self {
+        Self { sums: Some(HashMap::new()), ..Self::default() }
+    }
+
+    // the number of entries, counting repeated values once for each
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn insert(&mut self, value: T) {
+        let key = value.widen();
+
+        // the new entry pairs with every entry already there
+        if let Some(sums) = &mut self.sums {
+            for (other, (_, count)) in &self.entries {
+                *sums.entry(other.clone() + key.clone()).or_insert(0) += count;
+            }
+        }
+
+        self.entries.entry(key).or_insert((value, 0)).1 += 1;
+        self.len += 1;
+    }
+
+    // removes one entry with value, returning whether there was one
+    pub fn remove(&mut self, value: &T) -> bool {
+        let key = value.widen();
+
+        let count = match self.entries.get_mut(&key) {
+            Some((_, count)) => count,
+            None => return false
+        };
+
+        *count -= 1;
+
+        if *count == 0 {
+            self.entries.remove(&key);
+        }
+
+        self.len -= 1;
+
+        // and the removed entry paired with every entry left
+        if let Some(sums) = &mut self.sums {
+            for (other, (_, count)) in &self.entries {
+                let sum = other.clone() + key.clone();
+
+                if let Some(pairs) = sums.get_mut(&sum) {
+                    *pairs -= count;
+
+                    if *pairs == 0 {
+                        sums.remove(&sum);
+                    }
+                }
+            }
+        }
+
+        true
+    }
+
+    pub fn count(&self, value: &T) -> usize {
+        self.entries.get(&value.widen()).map_or(0, |&(_, count)| count)
+    }
+
+    // an entry which sums with value to target, where value is not itself
+    // one of the entries
+    pub fn pair_with(&self, value: &T, target: &T::Wide) -> Option<&T> {
+        self.entries.get(&(target.clone() - value.widen())).map(|(entry, _)| entry)
+    }
+
+    // the number of pairs of entries which sum to target
+    pub fn pairs_summing_to(&self, target: &T::Wide) -> usize {
+        if let Some(sums) = &self.sums {
+            return sums.get(target).copied().unwrap_or(0);
+        }
+
+        self.entries.iter()
+            .map(|(key, (value, count))| match self.entries.get(&(target.clone() - key.clone())) {
+                Some((complement, other)) => match value.cmp(complement) {
+                    Ordering::Less => count * other,
+                    Ordering::Equal => count * (count - 1) / 2,
+                    Ordering::Greater => 0
+                },
+                None => 0
+            })
+            .sum()
+    }
+
+    pub fn has_pair(&self, target: &T::Wide) -> bool {
+        match &self.sums {
+            Some(sums) => sums.contains_key(target),
+            None => self.find_pair(target).is_some()
+        }
+    }
+
+    // two distinct entries which sum to target, the smaller first, which looks
+    // up the complement of each distinct value
+    pub fn find_pair(&self, target: &T::Wide) -> Option<(&T, &T)> {
+        if self.sums.as_ref().is_some_and(|sums| !sums.contains_key(target)) {
+            return None;
+        }
+
+        self.entries.iter().find_map(|(key, (value, count))| {
+            let (complement, _) = self.entries.get(&(target.clone() - key.clone()))?;
+
+            // a value only pairs with itself if more than one entry has it
+            match value.cmp(complement) {
+                Ordering::Less => Some((value, complement)),
+                Ordering::Equal if *count > 1 => Some((value, complement)),
+                _ => None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bigint::BigInt;
+    use crate::property::{check, equal, Integers, Vecs};
+
+    #[test]
+    fn multiset() {
+        let mut sums = PairSums::new();
+
+        for &value in &[5i64, 7, 5, -3] {
+            sums.insert(value);
+        }
+
+        assert_eq!((sums.len(), sums.count(&5), sums.count(&8)), (4, 2, 0));
+        assert_eq!(sums.find_pair(&12), Some((&5, &7)));
+        assert_eq!(sums.find_pair(&10), Some((&5, &5)));
+        assert_eq!(sums.find_pair(&2), Some((&-3, &5)));
+        assert!(!sums.has_pair(&14));
+        assert_eq!(sums.pairs_summing_to(&12), 2);
+        assert_eq!(sums.pair_with(&13, &20), Some(&7));
+        assert_eq!(sums.pair_with(&13, &21), None);
+
+        assert!(sums.remove(&5));
+        assert!(!sums.has_pair(&10));
+        assert!(sums.remove(&5));
+        assert!(!sums.remove(&5));
+        assert_eq!(sums.len(), 2);
+        assert!(!sums.has_pair(&12));
+        assert_eq!(sums.find_pair(&4), Some((&-3, &7)));
+
+        assert!(PairSums::<i64>::new().is_empty());
+    }
+
+    #[test]
+    fn wide_sums() {
+        let mut sums = PairSums::new();
+        sums.insert(i64::MAX);
+        sums.insert(i64::MAX);
+        assert!(sums.has_pair(&(2 * i128::from(i64::MAX))));
+
+        let mut sums = PairSums::new();
+        sums.insert(BigInt::from(i64::MIN));
+        sums.insert(BigInt::from(-1));
+        assert!(sums.has_pair(&(BigInt::from(i64::MIN) - BigInt::from(1))));
+    }
+
+    #[test]
+    fn agrees_with_every_pair() {
+        let strategy = (Vecs { element: Integers(-20..20), lengths: 0..12 }, Integers(-40..40));
+
+        check(&strategy, |(numbers, target)| {
+            let pairs = (0..numbers.len())
+                .flat_map(|i| ((i + 1)..numbers.len()).map(move |j| (i, j)))
+                .filter(|&(i, j)| numbers[i] + numbers[j] == *target)
+                .count();
+
+            for mut sums in [PairSums::new(), PairSums::counting_sums()] {
+                numbers.iter().for_each(|&n| sums.insert(n));
+
+                equal(sums.has_pair(&i128::from(*target)), pairs > 0)?;
+                equal(sums.pairs_summing_to(&i128::from(*target)), pairs)?;
+
+                if let Some((&a, &b)) = sums.find_pair(&i128::from(*target)) {
+                    equal(a + b, *target)?;
+                }
+
+                // removing every entry again leaves no pairs behind
+                numbers.iter().for_each(|n| { sums.remove(n); });
+                equal(sums.sums.map_or(0, |sums| sums.len()), 0)?;
+            }
+
+            Ok(())
+        });
+    }
+
+    #[test]
+    fn queries_only_the_sums() {
+        let mut sums = PairSums::counting_sums();
+        (1..=25i64).for_each(|n| sums.insert(n));
+        sums.remove(&20);
+
+        // with the entries gone, only the counts of each sum can answer
+        sums.entries.clear();
+
+        assert!(sums.has_pair(&26));
+        assert!(sums.has_pair(&49));
+        assert!(!sums.has_pair(&50));
+        assert!(!sums.has_pair(&2));
+        assert_eq!(sums.pairs_summing_to(&21), 9);
+    }
+}
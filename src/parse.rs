@@ -0,0 +1,376 @@
+// Parsing of puzzle input into lines, records, fields, grids and lists, with
+// errors located by line and column so that they can be shown under the
+// offending text. CRLF line endings and trailing newlines are accepted
+// everywhere.
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use regex::{Captures, Regex};
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    // 1-indexed
+    pub line: usize,
+    // 1-indexed, in characters
+    pub column: usize,
+    // the whole offending line
+    pub text: String,
+    // the length in characters of the offending part of the line
+    pub len: usize,
+    pub expected: String
+}
+
+impl ParseError {
+    // column and len are byte offsets within text
+    pub fn new(line: usize, text: &str, column: usize, len: usize, expected: &str) -> Self {
+        let column = column.min(text.len());
+        let end = (column + len).min(text.len());
+
+        Self {
+            line,
+            column: text[..column].chars().count() + 1,
+            text: text.to_owned(),
+            len: text[column..end].chars().count(),
+            expected: expected.to_owned()
+        }
+    }
+
+    // the offending part of the line
+    pub fn found(&self) -> String {
+        self.text.chars().skip(self.column - 1).take(self.len).collect()
+    }
+
+    // the error with the line it occurred on, and the offending part of the
+    // line underlined
+    pub fn render(&self) -> String {
+        let number = self.line.to_string();
+        let margin = " ".repeat(number.len());
+
+        format!(
+            "error: expected {}\n{}--> line {}, column {}\n{} |\n{} | {}\n{} | {}{}",
+            self.expected, margin, self.line, self.column,
+            margin,
+            number, self.text,
+            margin, " ".repeat(self.column - 1), "^".repeat(self.len.max(1))
+        )
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}", self.line, self.column, self.expected)?;
+
+        match self.found() {
+            found if found.is_empty() => write!(f, ", found end of line"),
+            found => write!(f, ", found `{}`", found)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+// an error within a single line, which is located by the caller
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct LineError {
+    // byte offset within the line
+    pub column: usize,
+    // in bytes
+    pub len: usize,
+    pub expected: String
+}
+
+impl LineError {
+    pub fn new(column: usize, len: usize, expected: &str) -> Self {
+        Self { column, len, expected: expected.to_owned() }
+    }
+
+    // for when the line as a whole is wrong
+    pub fn whole(line: &str, expected: &str) -> Self {
+        Self::new(0, line.len(), expected)
+    }
+
+    // for an error in part of the line, which starts at offset
+    pub fn offset(self, offset: usize) -> Self {
+        Self { column: self.column + offset, ..self }
+    }
+
+    pub fn at_line(self, line_number: usize, line: &str) -> ParseError {
+        ParseError::new(line_number, line, self.column, self.len, &self.expected)
+    }
+}
+
+// lines with their 1-indexed line numbers, without any \r of CRLF endings
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.lines()
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .enumerate()
+        .map(|(index, line)| (index + 1, line))
+}
+
+// parses every line which isn't blank
+pub fn parse_lines<T, F>(input: &str, mut parse_line: F) -> Result<Vec<T>, ParseError>
+where F: FnMut(&str) -> Result<T, LineError> {
+    numbered_lines(input)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(number, line)| parse_line(line).map_err(|e| e.at_line(number, line)))
+        .collect()
+}
+
+// parses a line (or part of one) consisting of a single number, which may be
+// surrounded by whitespace
+pub fn parse_number<T: FromStr>(s: &str, expected: &str) -> Result<T, LineError> {
+    let trimmed = s.trim();
+    let offset = s.len() - s.trim_start().len();
+
+    trimmed.parse::<T>().map_err(|_| LineError::new(offset, trimmed.len(), expected))
+}
+
+// records separated by blank lines, each with the line number it starts on
+pub fn records(input: &str) -> Vec<(usize, &str)> {
+    let mut records = vec![];
+    let mut start: Option<(usize, usize)> = None;
+    let mut offset = 0;
+
+    for (index, line) in input.split('\n').enumerate() {
+        let line_end = offset + line.len();
+
+        if line.trim().is_empty() {
+            if let Some((number, record_start)) = start.take() {
+                records.push((number, &input[record_start..offset]));
+            }
+        } else if start.is_none() {
+            start = Some((index + 1, offset));
+        }
+
+        offset = line_end + 1;
+    }
+
+    if let Some((number, record_start)) = start {
+        records.push((number, &input[record_start..]));
+    }
+
+    records
+}
+
+// one integer per line
+pub fn parse_integers<T: FromStr>(input: &str, expected: &str) -> Result<Vec<T>, ParseError> {
+    parse_lines(input, |line| parse_number(line, expected))
+}
+
+// a record of lines, separated from the others by blank lines
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct Record<'a> {
+    // the 1-indexed line number the record starts on
+    pub line: usize,
+    pub text: &'a str
+}
+
+impl<'a> Record<'a> {
+    // the lines of the record, numbered within the whole input
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        let start = self.line - 1;
+
+        numbered_lines(self.text).map(move |(number, line)| (start + number, line))
+    }
+
+    pub fn parse_lines<T, F>(&self, mut parse_line: F) -> Result<Vec<T>, ParseError>
+    where F: FnMut(&'a str) -> Result<T, LineError> {
+        self.lines()
+            .map(|(number, line)| parse_line(line).map_err(|e| e.at_line(number, line)))
+            .collect()
+    }
+}
+
+pub fn parse_records<'a, T, F>(input: &'a str, parse_record: F) -> Result<Vec<T>, ParseError>
+where F: FnMut(Record<'a>) -> Result<T, ParseError> {
+    records(input).into_iter()
+        .map(|(line, text)| Record { line, text })
+        .map(parse_record)
+        .collect()
+}
+
+// whitespace separated `key:value` fields, with keys accepted by is_key
+pub fn parse_fields<'a, F>(line: &'a str, is_key: F, expected: &str) -> Result<Vec<(&'a str, &'a str)>, LineError>
+where F: Fn(&str) -> bool {
+    let mut offset = 0;
+    let mut fields = vec![];
+
+    for field in line.split(' ') {
+        match field.split_once(':') {
+            Some((key, value)) if is_key(key) => fields.push((key, value)),
+            None if field.trim().is_empty() => {}
+            _ => return Err(LineError::new(offset, field.len(), expected))
+        }
+
+        offset += field.len() + 1;
+    }
+
+    Ok(fields)
+}
+
+// a rectangular grid with one cell per character, expected describing the
+// characters accepted by parse_cell
+pub fn parse_grid<T, F>(input: &str, expected: &str, mut parse_cell: F) -> Result<Vec<Vec<T>>, ParseError>
+where F: FnMut(char) -> Option<T> {
+    let mut width = None;
+
+    parse_lines(input, |line| {
+        let row = line.char_indices()
+            .map(|(col, c)| parse_cell(c).ok_or_else(|| LineError::new(col, c.len_utf8(), expected)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        match *width.get_or_insert(row.len()) {
+            width if width == row.len() => Ok(row),
+            width => Err(LineError::whole(line, &format!("a row {} wide", width)))
+        }
+    })
+}
+
+// a comma separated list in which placeholder stands for a missing item
+pub fn parse_comma_list<T, F>(line: &str, placeholder: &str, mut parse_item: F) -> Result<Vec<Option<T>>, LineError>
+where F: FnMut(&str) -> Result<T, LineError> {
+    let mut offset = 0;
+
+    line.split(',')
+        .map(|item| {
+            let start = offset;
+            offset += item.len() + 1;
+
+            if item.trim() == placeholder {
+                Ok(None)
+            } else {
+                parse_item(item).map(Some).map_err(|e| e.offset(start))
+            }
+        })
+        .collect()
+}
+
+// a value built from the groups of a regex, such as a tuple of FromStr types
+pub trait FromCaptures: Sized {
+    fn from_captures(captures: &Captures, expected: &str) -> Result<Self, LineError>;
+}
+
+macro_rules! impl_from_captures {
+    ($($t:ident $index:tt),+) => {
+        impl<$($t: FromStr),+> FromCaptures for ($($t,)+) {
+            fn from_captures(captures: &Captures, expected: &str) -> Result<Self, LineError> {
+                Ok(($(capture::<$t>(captures, $index + 1, expected)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_captures!(A 0);
+impl_from_captures!(A 0, B 1);
+impl_from_captures!(A 0, B 1, C 2);
+impl_from_captures!(A 0, B 1, C 2, D 3);
+impl_from_captures!(A 0, B 1, C 2, D 3, E 4);
+
+fn capture<T: FromStr>(captures: &Captures, index: usize, expected: &str) -> Result<T, LineError> {
+    let capture = captures.get(index).ok_or_else(|| LineError::new(0, 0, expected))?;
+
+    capture.as_str().parse()
+        .map_err(|_| LineError::new(capture.start(), capture.as_str().len(), expected))
+}
+
+// the groups of a regex which must match the line, e.g. `(usize, char)` for
+// `^(\d+) (.)$`, with expected describing the format of the line
+pub fn parse_captures<T: FromCaptures>(regex: &Regex, line: &str, expected: &str) -> Result<T, LineError> {
+    let captures = regex.captures(line).ok_or_else(|| LineError::whole(line, expected))?;
+
+    T::from_captures(&captures, expected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render() {
+        let error = ParseError::new(12, "jmp +x", 4, 2, "a signed integer");
+
+        assert_eq!(error.to_string(), "line 12, column 5: expected a signed integer, found `+x`");
+        assert_eq!(error.render(), "\
+error: expected a signed integer
+  --> line 12, column 5
+   |
+12 | jmp +x
+   |     ^^");
+    }
+
+    #[test]
+    fn parse_lines_skips_blank_lines_and_crlf() {
+        let numbers = parse_lines("1\r\n2\n\n3\n", |line| parse_number::<i32>(line, "a number"));
+        assert_eq!(numbers, Ok(vec![1, 2, 3]));
+
+        let error = parse_lines("1\n\n x\n", |line| parse_number::<i32>(line, "a number")).unwrap_err();
+        assert_eq!((error.line, error.column, error.found()), (3, 2, "x".to_owned()));
+    }
+
+    #[test]
+    fn records_by_blank_lines() {
+        assert_eq!(records("a\nb\n\nc\r\n\r\n\nd\n"), vec![(1, "a\nb\n"), (4, "c\r\n"), (7, "d\n")]);
+        assert_eq!(records("\n\n"), vec![]);
+    }
+
+    #[test]
+    fn integers() {
+        assert_eq!(parse_integers::<i64>("-1\r\n2\r\n\r\n", "an integer"), Ok(vec![-1, 2]));
+        assert_eq!(parse_integers::<u8>("1\n256\n", "a byte").unwrap_err().to_string(),
+                   "line 2, column 1: expected a byte, found `256`");
+    }
+
+    #[test]
+    fn records_of_lines() {
+        let records = parse_records("ab\r\nc\r\n\r\nd\n", |record| record.parse_lines(Ok));
+        assert_eq!(records, Ok(vec![vec!["ab", "c"], vec!["d"]]));
+
+        let error = parse_records("a\n\nb\nc?\n", |record| record.parse_lines(|line| match line {
+            "c?" => Err(LineError::new(1, 1, "a letter")),
+            _ => Ok(line)
+        })).unwrap_err();
+        assert_eq!((error.line, error.column), (4, 2));
+    }
+
+    #[test]
+    fn fields() {
+        let is_key = |key: &str| key.len() == 3;
+
+        assert_eq!(parse_fields("ecl:gry  pid:#1 ", is_key, "a field"), Ok(vec![("ecl", "gry"), ("pid", "#1")]));
+        assert_eq!(parse_fields("ecl:gry pid", is_key, "a field"), Err(LineError::new(8, 3, "a field")));
+        assert_eq!(parse_fields("ecl:gry ab:c", is_key, "a field"), Err(LineError::new(8, 4, "a field")));
+    }
+
+    #[test]
+    fn grid() {
+        let cell = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None
+        };
+
+        assert_eq!(parse_grid("#.\r\n.#\r\n", "`#` or `.`", cell), Ok(vec![vec![true, false], vec![false, true]]));
+        assert_eq!(parse_grid("#.\n.#.\n", "`#` or `.`", cell).unwrap_err().to_string(),
+                   "line 2, column 1: expected a row 2 wide, found `.#.`");
+        assert_eq!(parse_grid("#.\n.x\n", "`#` or `.`", cell).unwrap_err().to_string(),
+                   "line 2, column 2: expected `#` or `.`, found `x`");
+    }
+
+    #[test]
+    fn comma_list() {
+        let id = |item: &str| parse_number::<u32>(item, "an id or `x`");
+
+        assert_eq!(parse_comma_list("7,x,13", "x", id), Ok(vec![Some(7), None, Some(13)]));
+        assert_eq!(parse_comma_list("7,x,y", "x", id), Err(LineError::new(4, 1, "an id or `x`")));
+    }
+
+    #[test]
+    fn captures() {
+        let regex = Regex::new(r"^(\d+)-(\d+) (.)$").unwrap();
+
+        assert_eq!(parse_captures(&regex, "1-3 a", "a policy"), Ok((1u8, 3u8, 'a')));
+        assert_eq!(parse_captures::<(u8, u8, char)>(&regex, "1-3a", "a policy"), Err(LineError::new(0, 4, "a policy")));
+        assert_eq!(parse_captures::<(u8, u8, char)>(&regex, "1-300 a", "a policy"), Err(LineError::new(2, 3, "a policy")));
+    }
+}
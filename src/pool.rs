@@ -0,0 +1,59 @@
+// A fixed number of threads working through a list of independent jobs, such
+// as running every solver at once.
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+// applies f to every item on up to threads threads, returning the results in
+// the order of the items
+pub fn map<T, R, F>(items: &[T], threads: usize, f: F) -> Vec<R>
+where T: Sync, R: Send, F: Fn(&T) -> R + Sync {
+    let next = AtomicUsize::new(0);
+    let results = Mutex::new(items.iter().map(|_| None).collect::<Vec<Option<R>>>());
+
+    thread::scope(|scope| {
+        for _ in 0..threads.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                // each thread takes the next item until there are none left
+                loop {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+
+                    let item = match items.get(index) {
+                        Some(item) => item,
+                        None => break
+                    };
+
+                    let result = f(item);
+                    results.lock().unwrap()[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    results.into_inner().unwrap().into_iter()
+        .map(|result| result.expect("every item is mapped"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Barrier;
+
+    #[test]
+    fn keeps_the_order() {
+        let items = (0..100).collect::<Vec<u64>>();
+
+        assert_eq!(map(&items, 4, |x| x * x), items.iter().map(|x| x * x).collect::<Vec<_>>());
+        assert_eq!(map(&items[..3], 16, |x| x + 1), vec![1, 2, 3]);
+        assert_eq!(map(&[] as &[u64], 4, |x| *x), vec![]);
+    }
+
+    #[test]
+    fn runs_concurrently() {
+        // would never return if the two items were mapped one after the other
+        let barrier = Barrier::new(2);
+
+        assert_eq!(map(&[1, 2], 2, |x| { barrier.wait(); *x }), vec![1, 2]);
+    }
+}
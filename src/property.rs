@@ -0,0 +1,273 @@
+// Property based testing: a property is checked against many generated values,
+// and a value it fails for is shrunk into a simpler one which still fails, so
+// that the failure is easier to follow. The values are generated from a fixed
+// seed, so a failure happens again on every run until it is fixed.
+use std::fmt::{self, Debug, Display};
+use std::ops::Range;
+
+use crate::rng::Rng;
+
+pub trait Strategy {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    // values simpler than value, the simplest first
+    fn shrink(&self, _value: &Self::Value) -> Vec<Self::Value> {
+        vec![]
+    }
+}
+
+// integers in a range, which shrink towards the one closest to 0
+pub struct Integers(pub Range<i64>);
+
+impl Strategy for Integers {
+    type Value = i64;
+
+    fn generate(&self, rng: &mut Rng) -> i64 {
+        rng.range(self.0.clone())
+    }
+
+    fn shrink(&self, &value: &i64) -> Vec<i64> {
+        let origin = 0.max(self.0.start).min(self.0.end - 1);
+        let mut candidates = vec![];
+
+        // halving the distance to the origin each time, from the origin itself
+        // to the integer next to value
+        let mut distance = value - origin;
+
+        while distance != 0 {
+            candidates.push(value - distance);
+            distance /= 2;
+        }
+
+        candidates
+    }
+}
+
+// vectors with a length in a range, which shrink by leaving elements out and
+// then by shrinking the elements themselves
+pub struct Vecs<S> {
+    pub element: S,
+    pub lengths: Range<usize>
+}
+
+impl<S: Strategy> Strategy for Vecs<S> {
+    type Value = Vec<S::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let len = self.lengths.start + rng.index(self.lengths.end - self.lengths.start);
+
+        (0..len).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let mut candidates = vec![];
+
+        // leaving out halves, then quarters and so on down to single elements
+        let mut chunk = value.len();
+
+        while chunk > 0 {
+            if value.len() - chunk >= self.lengths.start {
+                for start in (0..value.len()).step_by(chunk) {
+                    let mut shorter = value.clone();
+                    shorter.drain(start..(start + chunk).min(value.len()));
+                    candidates.push(shorter);
+                }
+            }
+
+            chunk /= 2;
+        }
+
+        for (index, element) in value.iter().enumerate() {
+            for simpler in self.element.shrink(element) {
+                let mut candidate = value.clone();
+                candidate[index] = simpler;
+                candidates.push(candidate);
+            }
+        }
+
+        candidates
+    }
+}
+
+// pairs shrink one side at a time
+impl<A: Strategy, B: Strategy> Strategy for (A, B) {
+    type Value = (A::Value, B::Value);
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        (self.0.generate(rng), self.1.generate(rng))
+    }
+
+    fn shrink(&self, (a, b): &Self::Value) -> Vec<Self::Value> {
+        let simpler_a = self.0.shrink(a).into_iter().map(|a| (a, b.clone()));
+        let simpler_b = self.1.shrink(b).into_iter().map(|b| (a.clone(), b));
+
+        simpler_a.chain(simpler_b).collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub cases: u32,
+    pub seed: u64,
+    // the most times a failing value is replaced by a simpler one
+    pub max_shrinks: u32
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { cases: 256, seed: 2020, max_shrinks: 1000 }
+    }
+}
+
+#[derive(Debug)]
+pub struct Failure<V> {
+    // the case that failed first, counting from 1, and its seed
+    pub case: u32,
+    pub seed: u64,
+    pub original: V,
+    pub shrunk: V,
+    pub shrinks: u32,
+    // what the property said about the shrunk value
+    pub message: String
+}
+
+impl<V: Debug> Display for Failure<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "property failed on case {} (seed {})", self.case, self.seed)?;
+        writeln!(f, "original value: {:?}", self.original)?;
+        writeln!(f, "shrunk {} times to: {:?}", self.shrinks, self.shrunk)?;
+        write!(f, "{}", self.message)
+    }
+}
+
+// panics with the shrunk value if the property fails for any of the values
+pub fn check<S, P>(strategy: &S, property: P)
+where S: Strategy, P: Fn(&S::Value) -> Result<(), String> {
+    check_with(&Config::default(), strategy, property)
+}
+
+pub fn check_with<S, P>(config: &Config, strategy: &S, property: P)
+where S: Strategy, P: Fn(&S::Value) -> Result<(), String> {
+    if let Some(failure) = falsify(config, strategy, property) {
+        panic!("{}", failure);
+    }
+}
+
+// returns the first value that the property fails for, shrunk, or None if it
+// holds for all of them
+pub fn falsify<S, P>(config: &Config, strategy: &S, property: P) -> Option<Failure<S::Value>>
+where S: Strategy, P: Fn(&S::Value) -> Result<(), String> {
+    let mut seeds = Rng::new(config.seed);
+
+    for case in 1..=config.cases {
+        let seed = seeds.next_u64();
+        let original = strategy.generate(&mut Rng::new(seed));
+
+        if let Err(message) = property(&original) {
+            let (shrunk, shrinks, message) = shrink(config, strategy, &property, original.clone(), message);
+
+            return Some(Failure { case, seed, original, shrunk, shrinks, message });
+        }
+    }
+
+    None
+}
+
+// keeps replacing value with the first simpler value which still fails, until
+// there is none
+fn shrink<S, P>(config: &Config, strategy: &S, property: &P, value: S::Value, message: String) -> (S::Value, u32, String)
+where S: Strategy, P: Fn(&S::Value) -> Result<(), String> {
+    let mut value = value;
+    let mut message = message;
+    let mut shrinks = 0;
+
+    'shrinking: while shrinks < config.max_shrinks {
+        for candidate in strategy.shrink(&value) {
+            if let Err(candidate_message) = property(&candidate) {
+                value = candidate;
+                message = candidate_message;
+                shrinks += 1;
+
+                continue 'shrinking;
+            }
+        }
+
+        break;
+    }
+
+    (value, shrinks, message)
+}
+
+// a property's way of asserting that two values are equal
+pub fn equal<T: PartialEq + Debug>(left: T, right: T) -> Result<(), String> {
+    if left == right {
+        Ok(())
+    } else {
+        Err(format!("{:?} != {:?}", left, right))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at_most(limit: i64) -> impl Fn(&i64) -> Result<(), String> {
+        move |&x| if x <= limit { Ok(()) } else { Err(format!("{} > {}", x, limit)) }
+    }
+
+    #[test]
+    fn integers_shrink_to_the_boundary() {
+        let failure = falsify(&Config::default(), &Integers(0..1000), at_most(99)).unwrap();
+
+        assert!(failure.original > 99);
+        assert_eq!(failure.shrunk, 100);
+        assert_eq!(failure.message, "100 > 99");
+
+        // towards the end of the range closest to 0
+        let failure = falsify(&Config::default(), &Integers(-1000..-10), |&x| equal(x >= -500, true)).unwrap();
+        assert_eq!(failure.shrunk, -501);
+    }
+
+    #[test]
+    fn vecs_shrink_to_the_failing_element() {
+        let strategy = Vecs { element: Integers(0..100), lengths: 0..50 };
+        let failure = falsify(&Config::default(), &strategy, |numbers| {
+            equal(numbers.iter().any(|&n| n >= 10), false)
+        }).unwrap();
+
+        assert_eq!(failure.shrunk, vec![10]);
+
+        // never shorter than the shortest length
+        let strategy = Vecs { element: Integers(0..100), lengths: 3..6 };
+        let failure = falsify(&Config::default(), &strategy, |_| Err(String::new())).unwrap();
+        assert_eq!(failure.shrunk, vec![0, 0, 0]);
+    }
+
+    #[test]
+    fn pairs_shrink_both_sides() {
+        let failure = falsify(&Config::default(), &(Integers(0..100), Integers(0..100)), |&(a, b)| {
+            if a + b < 50 { Ok(()) } else { Err(String::new()) }
+        }).unwrap();
+
+        assert_eq!(failure.shrunk.0 + failure.shrunk.1, 50);
+    }
+
+    #[test]
+    fn reproducible() {
+        let fails_often = |&x: &i64| if x % 7 != 3 { Ok(()) } else { Err(String::new()) };
+
+        let first = falsify(&Config::default(), &Integers(0..1000), fails_often).unwrap();
+        let second = falsify(&Config::default(), &Integers(0..1000), fails_often).unwrap();
+        assert_eq!((first.case, first.seed, first.original), (second.case, second.seed, second.original));
+
+        assert!(falsify(&Config::default(), &Integers(0..1000), at_most(1000)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "shrunk 0 times to: 5")]
+    fn check_panics() {
+        check(&Integers(5..6), at_most(4));
+    }
+}
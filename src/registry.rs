@@ -0,0 +1,292 @@
+// Every solver in the crate, listed by day, part and variant, so that they
+// can be discovered and run without knowing which module they live in.
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+use crate::*;
+use crate::number::Overflow;
+use crate::parse::ParseError;
+use crate::trace::Observer;
+
+pub struct Day {
+    pub day: u32,
+    pub title: &'static str,
+    // the bundled puzzle input
+    pub input: &'static str,
+    // the source of each part, which starts with the puzzle statement
+    pub sources: [&'static str; 2]
+}
+
+// parses and solves an input, timing each separately
+pub type Runner = fn(&str) -> Result<Run, SolveError>;
+
+pub struct Solver {
+    pub day: u32,
+    pub part: u32,
+    // None for the default solver of a part
+    pub variant: Option<&'static str>,
+    // without details, so that it can be timed over and over
+    pub run: Runner,
+    // as run, with the details of the answer, which can take longer than
+    // solving it, e.g. counting every combination of day 1
+    pub detailed: Runner,
+    // whether the input parses, without solving it
+    pub accepts: fn(&str) -> bool,
+    // solves the examples of the puzzle statement, for the few which differ
+    // from real inputs in a way the input doesn't show, such as the shorter
+    // preamble of day 9; None if they're solved by run
+    pub example: Option<Runner>
+}
+
+// a solver which shows each step of its iteration to an observer
+pub struct Tracer {
+    pub day: u32,
+    pub part: u32,
+    // parses and solves the input, returning the answer
+    pub trace: fn(&str, &mut dyn Observer) -> Result<Option<String>, SolveError>
+}
+
+// why a solver has no answer: the input doesn't parse, or the answer doesn't
+// fit in the integer type of the solver
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SolveError {
+    Parse(ParseError),
+    Overflow(Overflow)
+}
+
+impl SolveError {
+    // like ParseError::render, with the offending line if there is one
+    pub fn render(&self) -> String {
+        match self {
+            SolveError::Parse(e) => e.render(),
+            SolveError::Overflow(e) => format!("error: {}", e)
+        }
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(e) => write!(f, "{}", e),
+            SolveError::Overflow(e) => write!(f, "{}", e)
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(e: ParseError) -> Self {
+        SolveError::Parse(e)
+    }
+}
+
+impl From<Overflow> for SolveError {
+    fn from(e: Overflow) -> Self {
+        SolveError::Overflow(e)
+    }
+}
+
+// intermediate results worth reporting alongside the answer, as (name, value)
+pub type Details = Vec<(&'static str, String)>;
+
+pub struct Run {
+    // None if the input has no solution
+    pub answer: Option<String>,
+    pub details: Details,
+    pub parse_time: Duration,
+    pub solve_time: Duration
+}
+
+impl Run {
+    pub fn elapsed(&self) -> Duration {
+        self.parse_time + self.solve_time
+    }
+}
+
+macro_rules! sources {
+    ($module:ident) => {
+        [
+            include_str!(concat!(stringify!($module), "/part1.rs")),
+            include_str!(concat!(stringify!($module), "/part2.rs"))
+        ]
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    Day { day: 1, title: "Report Repair", input: day01::INPUT, sources: sources!(day01) },
+    Day { day: 2, title: "Password Philosophy", input: day02::INPUT, sources: sources!(day02) },
+    Day { day: 3, title: "Toboggan Trajectory", input: day03::INPUT, sources: sources!(day03) },
+    Day { day: 4, title: "Passport Processing", input: day04::INPUT, sources: sources!(day04) },
+    Day { day: 5, title: "Binary Boarding", input: day05::INPUT, sources: sources!(day05) },
+    Day { day: 6, title: "Custom Customs", input: day06::INPUT, sources: sources!(day06) },
+    Day { day: 7, title: "Handy Haversacks", input: day07::INPUT, sources: sources!(day07) },
+    Day { day: 8, title: "Handheld Halting", input: day08::INPUT, sources: sources!(day08) },
+    Day { day: 9, title: "Encoding Error", input: day09::INPUT, sources: sources!(day09) },
+    Day { day: 10, title: "Adapter Array", input: day10::INPUT, sources: sources!(day10) },
+    Day { day: 11, title: "Seating System", input: day11::INPUT, sources: sources!(day11) },
+    Day { day: 12, title: "Rain Risk", input: day12::INPUT, sources: sources!(day12) },
+    Day { day: 13, title: "Shuttle Search", input: day13::INPUT, sources: sources!(day13) },
+];
+
+// the solve and details functions are wrapped in closures so that they can
+// take the parsed input by reference, whatever the type returned by parse
+macro_rules! solver {
+    ($day:expr, $part:expr, $variant:expr, $parse:path, $solve:path) => {
+        Solver {
+            day: $day, part: $part, variant: $variant,
+            run: |input| timed(input, $parse, |parsed| $solve(parsed), |_| vec![]),
+            detailed: |input| timed(input, $parse, |parsed| $solve(parsed), |_| vec![]),
+            accepts: |input| $parse(input).is_ok(),
+            example: None
+        }
+    };
+    ($day:expr, $part:expr, $variant:expr, $parse:path, $solve:path, $details:path) => {
+        Solver {
+            run: |input| timed(input, $parse, |parsed| $solve(parsed), |_| vec![]),
+            detailed: |input| timed(input, $parse, |parsed| $solve(parsed), |parsed| $details(parsed)),
+            ..solver!($day, $part, $variant, $parse, $solve)
+        }
+    };
+    ($day:expr, $part:expr, $variant:expr, $parse:path, $solve:path $(, $details:path)?; example $example:path) => {
+        Solver {
+            example: Some(|input| timed(input, $parse, |parsed| $example(parsed), |_| vec![])),
+            ..solver!($day, $part, $variant, $parse, $solve $(, $details)?)
+        }
+    };
+}
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(1, 1, None, day01::parse, day01::part1::solve, day01::part1::details),
+    solver!(1, 1, Some("hashed"), day01::parse_unsorted, day01::part1_hashed::solve, day01::part1_hashed::details),
+    solver!(1, 1, Some("bigint"), day01::parse_big, day01::part1::solve, day01::part1::details),
+    solver!(1, 2, None, day01::parse, day01::part2::solve, day01::part2::details),
+    solver!(1, 2, Some("bigint"), day01::parse_big, day01::part2::solve, day01::part2::details),
+    solver!(2, 1, None, day02::parse, day02::part1::solve, day02::part1::details),
+    solver!(2, 2, None, day02::parse, day02::part2::solve, day02::part2::details),
+    solver!(3, 1, None, day03::parse, day03::part1::solve),
+    solver!(3, 2, None, day03::parse, day03::part2::solve, day03::part2::details),
+    solver!(4, 1, None, day04::parse, day04::part1::solve, day04::part1::details),
+    solver!(4, 2, None, day04::parse, day04::part2::solve, day04::part2::details),
+    solver!(5, 1, None, day05::parse, day05::part1::solve, day05::part1::details),
+    solver!(5, 2, None, day05::parse, day05::part2::solve, day05::part2::details),
+    solver!(6, 1, None, day06::parse, day06::part1::solve),
+    solver!(6, 2, None, day06::parse, day06::part2::solve),
+    solver!(7, 1, None, day07::part1::parse, day07::part1::solve),
+    solver!(7, 2, None, day07::part2::parse, day07::part2::solve),
+    solver!(8, 1, None, day08::parse, day08::part1::solve),
+    solver!(8, 2, None, day08::parse, day08::part2::solve, day08::part2::details),
+    solver!(
+        8, 2, Some("optimized"),
+        day08::parse, day08::part2_optimized::solve, day08::part2_optimized::details
+    ),
+    solver!(9, 1, None, day09::parse, day09::part1::solve; example day09::part1::solve_example),
+    solver!(9, 2, None, day09::parse, day09::part2::solve, day09::part2::details; example day09::part2::solve_example),
+    solver!(10, 1, None, day10::parse, day10::part1::solve, day10::part1::details),
+    solver!(10, 2, None, day10::parse, day10::part2::solve),
+    solver!(11, 1, None, day11::parse, day11::part1::solve),
+    solver!(11, 2, None, day11::parse, day11::part2::solve),
+    solver!(12, 1, None, day12::part1::parse, day12::part1::solve, day12::part1::details),
+    solver!(12, 2, None, day12::part2::parse, day12::part2::solve, day12::part2::details),
+    solver!(13, 1, None, day13::part1::parse, day13::part1::solve, day13::part1::details),
+    solver!(13, 2, None, day13::part2::parse, day13::part2::solve),
+];
+
+macro_rules! tracer {
+    ($day:expr, $part:expr, $parse:path, $trace:path) => {
+        Tracer {
+            day: $day, part: $part,
+            trace: |input, observer| $trace(&$parse(input)?, observer).into_answer()
+        }
+    };
+}
+
+pub const TRACERS: &[Tracer] = &[
+    tracer!(11, 1, day11::parse, day11::part1::trace),
+    tracer!(11, 2, day11::parse, day11::part2::trace),
+    tracer!(12, 1, day12::part1::parse, day12::part1::trace),
+    tracer!(12, 2, day12::part2::parse, day12::part2::trace),
+    tracer!(13, 2, day13::part2::parse, day13::part2::trace),
+];
+
+pub fn day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+pub fn find(day: u32, part: u32, variant: Option<&str>) -> Option<&'static Solver> {
+    SOLVERS.iter().find(|s| s.day == day && s.part == part && s.variant == variant)
+}
+
+pub fn tracer(day: u32, part: u32) -> Option<&'static Tracer> {
+    TRACERS.iter().find(|t| t.day == day && t.part == part)
+}
+
+impl Solver {
+    pub fn title(&self) -> &'static str {
+        day(self.day).map(|d| d.title).unwrap_or("")
+    }
+
+    pub fn bundled_input(&self) -> &'static str {
+        day(self.day).map(|d| d.input).unwrap_or("")
+    }
+
+    // solves an example of the puzzle statement
+    pub fn run_example(&self, input: &str) -> Result<Run, SolveError> {
+        self.example.unwrap_or(self.run)(input)
+    }
+
+    // e.g. "8.2 (optimized)"
+    pub fn label(&self) -> String {
+        match self.variant {
+            Some(variant) => format!("{}.{} ({})", self.day, self.part, variant),
+            None => format!("{}.{}", self.day, self.part)
+        }
+    }
+}
+
+// details are computed after, and not included in, the timings
+pub fn timed<'a, P, A, F, G, H>(input: &'a str, parse: F, solve: G, details: H) -> Result<Run, SolveError>
+where F: Fn(&'a str) -> Result<P, ParseError>, G: Fn(&P) -> A, H: Fn(&P) -> Details, A: IntoAnswer {
+    let start = Instant::now();
+    let parsed = parse(input)?;
+    let parse_time = start.elapsed();
+
+    let start = Instant::now();
+    let answer = solve(&parsed);
+    let solve_time = start.elapsed();
+
+    let details = details(&parsed);
+
+    Ok(Run { answer: answer.into_answer()?, details, parse_time, solve_time })
+}
+
+// converts the typed answer of each part into its printed form
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<Option<String>, SolveError>;
+}
+
+impl<T: Display> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Result<Option<String>, SolveError> {
+        Ok(self.map(|answer| answer.to_string()))
+    }
+}
+
+// for answers computed with checked arithmetic
+impl<T: IntoAnswer> IntoAnswer for Result<T, Overflow> {
+    fn into_answer(self) -> Result<Option<String>, SolveError> {
+        self?.into_answer()
+    }
+}
+
+macro_rules! impl_into_answer {
+    ($($t:ty),*) => {
+        $(impl IntoAnswer for $t {
+            fn into_answer(self) -> Result<Option<String>, SolveError> {
+                Ok(Some(self.to_string()))
+            }
+        })*
+    };
+}
+
+impl_into_answer!(i32, i64, usize);
@@ -0,0 +1,104 @@
+// A small deterministic pseudo-random number generator (SplitMix64), so that
+// generated inputs can be reproduced from their seed on any machine.
+use std::ops::Range;
+
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    // uniform in 0..n; n must not be 0
+    pub fn below(&mut self, n: u64) -> u64 {
+        // rejecting the top of the range avoids favouring small numbers
+        let limit = u64::MAX - u64::MAX % n;
+
+        loop {
+            let x = self.next_u64();
+
+            if x < limit {
+                return x % n;
+            }
+        }
+    }
+
+    // uniform in range; the range must not be empty
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        let width = range.end.wrapping_sub(range.start) as u64;
+
+        range.start.wrapping_add(self.below(width) as i64)
+    }
+
+    // a uniform index into a collection of len items
+    pub fn index(&mut self, len: usize) -> usize {
+        self.below(len as u64) as usize
+    }
+
+    // true with probability numerator / denominator
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    // Fisher-Yates
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deterministic() {
+        let first = (0..5).map({ let mut rng = Rng::new(7); move |_| rng.next_u64() }).collect::<Vec<_>>();
+        let second = (0..5).map({ let mut rng = Rng::new(7); move |_| rng.next_u64() }).collect::<Vec<_>>();
+
+        assert_eq!(first, second);
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn within_bounds() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 5];
+
+        for _ in 0..1000 {
+            let x = rng.range(-2..3);
+            assert!((-2..3).contains(&x));
+            seen[(x + 2) as usize] = true;
+        }
+
+        assert!(seen.iter().all(|&s| s));
+        assert_eq!(rng.range(i64::MIN..i64::MIN + 1), i64::MIN);
+    }
+
+    #[test]
+    fn shuffle_keeps_items() {
+        let mut items = (0..20).collect::<Vec<_>>();
+        Rng::new(3).shuffle(&mut items);
+
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
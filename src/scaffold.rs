@@ -0,0 +1,416 @@
+// Sets up a new day in the same layout as the existing ones: the day module
+// with its part modules, bundled input and example tests, a binary for each
+// part, and the entries registering it with the library and the runner.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// the crate this was built from, where new days are created by default
+pub const ROOT: &str = env!("CARGO_MANIFEST_DIR");
+
+const DAY_MODULE: &str = "\
+use crate::parse::{parse_lines, ParseError};
+
+pub mod part1;
+pub mod part2;
+
+pub use part1::part1;
+pub use part2::part2;
+
+pub const INPUT: &str = include_str!(\"input\");
+
+pub fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+    parse_lines(input, |line| Ok(line.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = \"\\
+\";
+
+    #[test]
+    fn part1_example() {
+        assert_eq!(part1(EXAMPLE), Ok(0));
+    }
+
+    #[test]
+    fn part2_example() {
+        assert_eq!(part2(EXAMPLE), Ok(0));
+    }
+}
+";
+
+pub struct Scaffold {
+    pub day: u32,
+    // used to name the binaries, e.g. "handy-haversacks"
+    pub slug: String,
+    pub title: String
+}
+
+impl Scaffold {
+    // the title defaults to the slug in title case
+    pub fn new(day: u32, slug: &str, title: Option<&str>) -> Result<Self, String> {
+        if !(1..=25).contains(&day) {
+            return Err(format!("invalid day: {}, must be from 1 to 25", day));
+        }
+
+        let valid_slug = !slug.is_empty()
+            && slug.split('-').all(|word| !word.is_empty())
+            && slug.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+
+        if !valid_slug {
+            return Err(format!("invalid slug: {}, must be lowercase words separated by dashes", slug));
+        }
+
+        let title = match title {
+            Some(title) => title.to_owned(),
+            None => title_case(slug)
+        };
+
+        Ok(Self { day, slug: slug.to_owned(), title })
+    }
+
+    pub fn module(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    // the new files, relative to the root of the crate
+    pub fn files(&self) -> Vec<(PathBuf, String)> {
+        let module = Path::new("src").join(self.module());
+        let binary = format!("{}-{}", self.day, self.slug);
+
+        vec![
+            (module.join("mod.rs"), DAY_MODULE.to_owned()),
+            (module.join("part1.rs"), self.part_module(1)),
+            (module.join("part2.rs"), self.part_module(2)),
+            (module.join("input"), String::new()),
+            (Path::new("src/bin").join(&binary).join("main.rs"), self.binary(1)),
+            (Path::new("src/bin").join(format!("{}-part-2", binary)).join("main.rs"), self.binary(2))
+        ]
+    }
+
+    fn part_module(&self, part: u32) -> String {
+        let heading = match part {
+            1 => format!("--- Day {}: {} ---", self.day, self.title),
+            _ => "--- Part Two ---".to_owned()
+        };
+
+        format!("\
+/* {heading}
+
+TODO: the puzzle description.
+*/
+use crate::parse::ParseError;
+
+use super::parse;
+
+pub fn part{part}(input: &str) -> Result<usize, ParseError> {{
+    Ok(solve(&parse(input)?))
+}}
+
+pub fn solve(lines: &[String]) -> usize {{
+    lines.len()
+}}
+", heading = heading, part = part)
+    }
+
+    fn binary(&self, part: u32) -> String {
+        format!("\
+use advent_of_code_2020::{{{module}, input}};
+
+fn main() {{
+    let input = input::from_args({module}::INPUT);
+
+    let result = input::or_exit({module}::part{part}(&input));
+
+    println!(\"result: {{}}\", result);
+}}
+", module = self.module(), part = part)
+    }
+
+    // adds `pub mod dayNN;` to src/lib.rs, keeping the days in order
+    pub fn add_module(&self, lib: &str) -> Result<String, String> {
+        let line = format!("pub mod {};", self.module());
+
+        if lib.lines().any(|l| l.trim() == line) {
+            return Err(format!("src/lib.rs already has {}", line));
+        }
+
+        let position = insert_position(lib, "pub mod day", self.day)
+            .ok_or("src/lib.rs has no day modules to add to")?;
+
+        Ok(format!("{}{}\n{}", &lib[..position], line, &lib[position..]))
+    }
+
+    // adds the day to DAYS, and a solver for each part to SOLVERS
+    pub fn add_to_registry(&self, registry: &str) -> Result<String, String> {
+        if find_entries(registry, "Day { day: ").iter().any(|&(_, day)| day == self.day) {
+            return Err(format!("the registry already has day {}", self.day));
+        }
+
+        let module = self.module();
+        let day = format!(
+            "    Day {{ day: {}, title: {:?}, input: {}::INPUT, sources: sources!({}) }},\n",
+            self.day, self.title, module, module
+        );
+        let solvers = (1..=2)
+            .map(|part| format!(
+                "    solver!({}, {}, None, {}::parse, {}::part{}::solve),\n",
+                self.day, part, module, module, part
+            ))
+            .collect::<String>();
+
+        // solvers come after the days, so insert them first
+        let position = insert_position(registry, "solver!(", self.day)
+            .ok_or("the registry has no solvers to add to")?;
+        let registry = format!("{}{}{}", &registry[..position], solvers, &registry[position..]);
+
+        let position = insert_position(&registry, "Day { day: ", self.day)
+            .ok_or("the registry has no days to add to")?;
+
+        Ok(format!("{}{}{}", &registry[..position], day, &registry[position..]))
+    }
+
+    // writes the new files and registers the day, relative to the crate root;
+    // returns every path written
+    pub fn create(&self, root: &Path) -> Result<Vec<PathBuf>, String> {
+        let module = root.join("src").join(self.module());
+
+        if module.exists() {
+            return Err(format!("{} already exists", module.display()));
+        }
+
+        let lib_path = root.join("src/lib.rs");
+        let registry_path = root.join("src/registry.rs");
+
+        let lib = self.add_module(&read(&lib_path)?)?;
+        let registry = self.add_to_registry(&read(&registry_path)?)?;
+
+        let mut written = vec![];
+
+        for (path, contents) in self.files() {
+            let path = root.join(path);
+
+            if path.exists() {
+                return Err(format!("{} already exists", path.display()));
+            }
+
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)
+                    .map_err(|e| format!("failed to create {}: {}", parent.display(), e))?;
+            }
+
+            write(&path, &contents)?;
+            written.push(path);
+        }
+
+        write(&lib_path, &lib)?;
+        write(&registry_path, &registry)?;
+        written.push(lib_path);
+        written.push(registry_path);
+
+        Ok(written)
+    }
+}
+
+// "handy-haversacks" -> "Handy Haversacks"
+pub fn title_case(slug: &str) -> String {
+    slug.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// the start of each line containing marker followed by a day number, with
+// that day
+fn find_entries(source: &str, marker: &str) -> Vec<(usize, u32)> {
+    let mut entries = vec![];
+    let mut line_start = 0;
+
+    for line in source.split_inclusive('\n') {
+        if let Some(index) = line.find(marker) {
+            let rest = line[(index + marker.len())..].trim_start();
+            let digits = rest.chars().take_while(|c| c.is_ascii_digit()).collect::<String>();
+
+            // multi-line entries have the day on the next line
+            let day = match digits.parse::<u32>() {
+                Ok(day) => Some(day),
+                Err(_) => source[(line_start + line.len())..].trim_start()
+                    .split(|c: char| !c.is_ascii_digit())
+                    .next()
+                    .and_then(|digits| digits.parse::<u32>().ok())
+            };
+
+            if let Some(day) = day {
+                entries.push((line_start, day));
+            }
+        }
+
+        line_start += line.len();
+    }
+
+    entries
+}
+
+// where to insert a line for day so that the entries stay in order: before the
+// first entry for a later day, or after the last entry otherwise
+fn insert_position(source: &str, marker: &str, day: u32) -> Option<usize> {
+    let entries = find_entries(source, marker);
+
+    if let Some(&(start, _)) = entries.iter().find(|&&(_, d)| d > day) {
+        return Some(start);
+    }
+
+    let &(last, _) = entries.last()?;
+
+    // skip to the end of the last entry, which may span several lines
+    let mut depth = 0;
+    let mut end = last;
+
+    for line in source[last..].split_inclusive('\n') {
+        depth += line.matches('(').count() as i32 - line.matches(')').count() as i32;
+        end += line.len();
+
+        if depth <= 0 {
+            break;
+        }
+    }
+
+    Some(end)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("failed to read {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::Solver;
+    use crate::statement;
+
+    const LIB: &str = "\
+pub mod day01;
+pub mod day03;
+
+pub mod parse;
+pub mod registry;
+";
+
+    const REGISTRY: &str = "\
+pub const DAYS: &[Day] = &[
+    Day { day: 1, title: \"Report Repair\", input: day01::INPUT, sources: sources!(day01) },
+    Day { day: 3, title: \"Toboggan Trajectory\", input: day03::INPUT, sources: sources!(day03) },
+];
+
+pub const SOLVERS: &[Solver] = &[
+    solver!(1, 1, None, day01::parse, day01::part1::solve),
+    solver!(
+        1, 2, Some(\"fast\"),
+        day01::parse, day01::part2::solve
+    ),
+    solver!(3, 1, None, day03::parse, day03::part1::solve),
+];
+";
+
+    #[test]
+    fn validates_arguments() {
+        assert_eq!(Scaffold::new(14, "docking-data", None).unwrap().title, "Docking Data");
+        assert_eq!(Scaffold::new(14, "docking-data", Some("Docking Data!")).unwrap().title, "Docking Data!");
+
+        assert!(Scaffold::new(0, "docking-data", None).is_err());
+        assert!(Scaffold::new(26, "docking-data", None).is_err());
+        assert!(Scaffold::new(14, "Docking Data", None).is_err());
+        assert!(Scaffold::new(14, "docking--data", None).is_err());
+    }
+
+    #[test]
+    fn add_module_in_order() {
+        let lib = Scaffold::new(2, "password-philosophy", None).unwrap().add_module(LIB).unwrap();
+        assert!(lib.starts_with("pub mod day01;\npub mod day02;\npub mod day03;\n\n"));
+
+        let lib = Scaffold::new(14, "docking-data", None).unwrap().add_module(LIB).unwrap();
+        assert!(lib.starts_with("pub mod day01;\npub mod day03;\npub mod day14;\n\n"));
+
+        assert!(Scaffold::new(3, "toboggan-trajectory", None).unwrap().add_module(LIB).is_err());
+    }
+
+    #[test]
+    fn add_to_registry_in_order() {
+        let registry = Scaffold::new(2, "password-philosophy", None).unwrap()
+            .add_to_registry(REGISTRY).unwrap();
+
+        assert!(registry.contains(
+            "sources!(day01) },\n    \
+             Day { day: 2, title: \"Password Philosophy\", input: day02::INPUT, sources: sources!(day02) },\n    \
+             Day { day: 3,"
+        ));
+
+        // after the multi-line solver for day 1
+        assert!(registry.contains(
+            "day01::part2::solve\n    ),\n    \
+             solver!(2, 1, None, day02::parse, day02::part1::solve),\n    \
+             solver!(2, 2, None, day02::parse, day02::part2::solve),\n    \
+             solver!(3, 1,"
+        ));
+
+        let registry = Scaffold::new(14, "docking-data", None).unwrap()
+            .add_to_registry(REGISTRY).unwrap();
+
+        assert!(registry.contains("solver!(14, 2, None, day14::parse, day14::part2::solve),\n];"));
+
+        assert!(Scaffold::new(3, "toboggan-trajectory", None).unwrap().add_to_registry(REGISTRY).is_err());
+    }
+
+    #[test]
+    fn no_examples() {
+        // the parse of a new day accepts any lines, so the placeholder for the
+        // statement mustn't read as an example
+        let solver = Solver {
+            day: 14, part: 1, variant: None,
+            run: |_| unreachable!(),
+            detailed: |_| unreachable!(),
+            accepts: |_| true,
+            example: None
+        };
+
+        let scaffold = Scaffold::new(14, "docking-data", None).unwrap();
+
+        for part in 1..=2 {
+            let (leading, examples) = statement::source_examples(&scaffold.part_module(part), &solver).unwrap();
+            assert!(leading.is_empty() && examples.is_empty());
+        }
+    }
+
+    #[test]
+    fn create_files() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+        fs::write(root.join("src/registry.rs"), REGISTRY).unwrap();
+
+        let scaffold = Scaffold::new(14, "docking-data", None).unwrap();
+        let written = scaffold.create(&root).unwrap();
+
+        assert_eq!(written.len(), 8);
+        assert!(root.join("src/day14/input").exists());
+        assert!(root.join("src/bin/14-docking-data-part-2/main.rs").exists());
+        assert!(fs::read_to_string(root.join("src/lib.rs")).unwrap().contains("pub mod day14;"));
+
+        // refuses to overwrite an existing day
+        assert!(scaffold.create(&root).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,286 @@
+// The puzzle statements, read back out of the leading `/* --- Day N: ... */`
+// comment of each part, with their examples and, where the text states it,
+// the expected answer of each example.
+use lazy_static::lazy_static;
+use regex::Regex;
+
+use crate::parse::records;
+use crate::registry::{self, Solver};
+
+pub struct Statement {
+    pub day: u32,
+    pub part: u32,
+    pub title: &'static str,
+    // e.g. "Day 7: Handy Haversacks" or "Part Two"
+    pub heading: &'static str,
+    // the text of the statement, without its heading
+    pub text: &'static str,
+    pub examples: Vec<Example>
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub input: String,
+    // None if the statement doesn't say
+    pub answer: Option<String>
+}
+
+#[derive(Debug, PartialEq)]
+enum Block<'a> {
+    Prose(&'a str),
+    // indented lines, which are a list rather than a code block
+    List(&'a str),
+    // consecutive code paragraphs are one block, as the blank lines between
+    // them are part of the code
+    Code(String)
+}
+
+lazy_static! {
+    // the ways in which the statements give the answer of an example, e.g.
+    // "the correct answer is 514579", "3 + 3 + 3 + 1 + 1 = 11." or
+    // "In the above example, 2 passwords are valid."
+    static ref ANSWER: Regex = Regex::new(r"\banswer(?: is|,)? (\d+)\b").unwrap();
+    static ref RESULT: Regex = Regex::new(
+        r"(?:= |\b(?:is|gives|producing|contains the value) )(\d+)(?: \w+)?(?: \([^)]*\))?[.!:]?$"
+    ).unwrap();
+    static ref IN_EXAMPLE: Regex = Regex::new(r"^In (?:this|the above) example, \D*?(\d+)\D*$").unwrap();
+}
+
+// None if the part has no statement
+pub fn statement(day: u32, part: u32) -> Option<Statement> {
+    let (heading, text) = comment(source(day, part)?)?;
+
+    Some(Statement {
+        day,
+        part,
+        title: registry::day(day).map(|d| d.title).unwrap_or(""),
+        heading,
+        text,
+        examples: examples(day, part)
+    })
+}
+
+fn source(day: u32, part: u32) -> Option<&'static str> {
+    let index = (part as usize).checked_sub(1)?;
+
+    registry::day(day).and_then(|d| d.sources.get(index)).copied()
+}
+
+// the examples are the code blocks that the part accepts as input, which
+// leaves out the worked examples; a second part without examples of its own
+// reuses the first part's
+pub fn examples(day: u32, part: u32) -> Vec<Example> {
+    let solver = match registry::find(day, part, None) {
+        Some(solver) => solver,
+        None => return vec![]
+    };
+
+    let (leading, mut examples) = match source(day, part).and_then(|source| source_examples(source, solver)) {
+        Some(examples) => examples,
+        None => return vec![]
+    };
+
+    if part == 1 || (leading.is_empty() && !examples.is_empty()) {
+        return examples;
+    }
+
+    // answers given before any example of this part belong to the examples
+    // of the first part, in order
+    let mut answers = leading.into_iter();
+    let mut inherited = self::examples(day, 1).into_iter()
+        .filter(|example| (solver.accepts)(&example.input))
+        .map(|example| Example { input: example.input, answer: answers.next() })
+        .collect::<Vec<_>>();
+
+    inherited.append(&mut examples);
+    inherited
+}
+
+// the answers given before the first example and the examples of the
+// statement that starts source, or None if it doesn't start with one
+pub fn source_examples(source: &str, solver: &Solver) -> Option<(Vec<String>, Vec<Example>)> {
+    comment(source).map(|(_, text)| own_examples(text, solver))
+}
+
+// returns the answers given before the first example, and the examples
+fn own_examples(text: &str, solver: &Solver) -> (Vec<String>, Vec<Example>) {
+    let mut leading = vec![];
+    let mut examples: Vec<Example> = vec![];
+
+    for block in blocks(text) {
+        match block {
+            Block::Code(code) if (solver.accepts)(&code) => {
+                examples.push(Example { input: code, answer: None });
+            }
+            Block::Prose(prose) => {
+                if let Some(answer) = answer(prose) {
+                    match examples.last_mut() {
+                        Some(example) => example.answer = Some(answer),
+                        None => leading.push(answer)
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    (leading, examples)
+}
+
+// returns the heading and the text of the comment that starts source, if it
+// is a statement
+fn comment(source: &str) -> Option<(&str, &str)> {
+    let comment = source.trim_start().strip_prefix("/*")?;
+    let comment = &comment[..comment.find("*/")?];
+
+    let (heading, text) = comment.split_once('\n').unwrap_or((comment, ""));
+    let heading = heading.trim().strip_prefix("---")?.strip_suffix("---")?.trim();
+
+    Some((heading, text.trim()))
+}
+
+fn blocks(text: &str) -> Vec<Block<'_>> {
+    let mut blocks = vec![];
+
+    for (_, paragraph) in records(text) {
+        let paragraph = paragraph.trim_end();
+        let lines = paragraph.lines().collect::<Vec<_>>();
+
+        let block = if lines.iter().all(|line| line.starts_with(char::is_whitespace)) {
+            Block::List(paragraph)
+        } else if lines.len() == 1 && paragraph.ends_with(&['.', '?', '!', ':', ')', '"'][..]) {
+            Block::Prose(paragraph)
+        } else {
+            Block::Code(paragraph.to_owned())
+        };
+
+        match (blocks.last_mut(), block) {
+            (Some(Block::Code(code)), Block::Code(next)) => {
+                code.push_str("\n\n");
+                code.push_str(&next);
+            }
+            (_, block) => blocks.push(block)
+        }
+    }
+
+    blocks
+}
+
+// the last answer given in a paragraph
+fn answer(prose: &str) -> Option<String> {
+    sentences(prose)
+        .filter_map(|sentence| {
+            ANSWER.captures(sentence)
+                .or_else(|| RESULT.captures(sentence))
+                .or_else(|| IN_EXAMPLE.captures(sentence))
+        })
+        .last()
+        .map(|captures| captures[1].to_owned())
+}
+
+fn sentences(prose: &str) -> impl Iterator<Item = &str> {
+    let mut rest = prose;
+
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+
+        let end = rest.find(". ")
+            .into_iter()
+            .chain(rest.find("! "))
+            .chain(rest.find("? "))
+            .min()
+            .map(|index| index + 1)
+            .unwrap_or_else(|| rest.len());
+
+        let sentence = &rest[..end];
+        rest = rest[end..].trim_start();
+
+        Some(sentence)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the answer of each example, or - if it isn't given
+    fn answers(day: u32, part: u32) -> Vec<String> {
+        examples(day, part).into_iter()
+            .map(|example| example.answer.unwrap_or_else(|| "-".to_owned()))
+            .collect()
+    }
+
+    #[test]
+    fn headings() {
+        let source = "/* --- Day 7: Handy Haversacks ---\n\nText\n*/\nuse x;";
+        assert_eq!(comment(source), Some(("Day 7: Handy Haversacks", "Text")));
+
+        let source = "/*--- Day 6: Custom Customs ---\n\nText\n*/";
+        assert_eq!(comment(source), Some(("Day 6: Custom Customs", "Text")));
+
+        assert_eq!(comment("/* --- Optimization Note ---\nText */"), Some(("Optimization Note", "Text")));
+        assert_eq!(comment("use crate::parse::ParseError;"), None);
+    }
+
+    #[test]
+    fn code_blocks() {
+        let text = "For example:\n\nabc\n\na\nb\n\n    a list\n    of things\n\nab\n\nThat is 3.";
+
+        assert_eq!(blocks(text), vec![
+            Block::Prose("For example:"),
+            Block::Code("abc\n\na\nb".to_owned()),
+            Block::List("    a list\n    of things"),
+            Block::Code("ab".to_owned()),
+            Block::Prose("That is 3.")
+        ]);
+    }
+
+    #[test]
+    fn stated_answers() {
+        assert_eq!(answers(1, 1), vec!["514579"]);
+        assert_eq!(answers(1, 2), vec!["241861950"]);
+        assert_eq!(answers(2, 1), vec!["2"]);
+        assert_eq!(answers(2, 2), vec!["-"]);
+        assert_eq!(answers(3, 1), vec!["7"]);
+        assert_eq!(answers(3, 2), vec!["336"]);
+        assert_eq!(answers(4, 2), vec!["-", "-"]);
+        assert_eq!(answers(5, 1), Vec::<String>::new());
+        assert_eq!(answers(6, 1), vec!["-", "11"]);
+        assert_eq!(answers(6, 2), vec!["6"]);
+        assert_eq!(answers(7, 1), vec!["4"]);
+        assert_eq!(answers(7, 2), vec!["32", "126"]);
+        assert_eq!(answers(8, 1), vec!["5"]);
+        assert_eq!(answers(8, 2), vec!["8"]);
+        assert_eq!(answers(10, 2), vec!["8", "-"]);
+        assert_eq!(answers(11, 1), Vec::<String>::new());
+        assert_eq!(answers(12, 1), vec!["25"]);
+        assert_eq!(answers(12, 2), vec!["286"]);
+        assert_eq!(answers(13, 1), vec!["295"]);
+        assert_eq!(answers(13, 2), vec!["1068781"]);
+    }
+
+    #[test]
+    fn solvers_agree_with_the_examples() {
+        for solver in registry::SOLVERS {
+            for example in examples(solver.day, solver.part) {
+                if let Some(expected) = example.answer {
+                    let run = solver.run_example(&example.input).unwrap();
+                    assert_eq!(run.answer, Some(expected), "{}", solver.label());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn missing_statements() {
+        assert!(statement(11, 1).is_none());
+        assert!(statement(1, 3).is_none());
+        assert!(statement(1, 0).is_none());
+
+        let statement = statement(7, 2).unwrap();
+        assert_eq!(statement.title, "Handy Haversacks");
+        assert!(statement.text.starts_with("It's getting pretty expensive"));
+    }
+}
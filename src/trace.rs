@@ -0,0 +1,108 @@
+// Observers which iterative solvers report each step to, so that a run can be
+// followed without editing print statements in and out of the solver.
+use std::fmt::Display;
+use std::io::Write;
+
+pub trait Observer {
+    // the state after each step, such as the whole grid or a position
+    fn step(&mut self, state: &dyn Display);
+
+    // an intermediate result within a step, only worth showing when verbose
+    fn note(&mut self, _message: &dyn Display) {}
+}
+
+// observes nothing, for solving without a trace
+impl Observer for () {
+    fn step(&mut self, _state: &dyn Display) {}
+}
+
+// writes every step and note as it happens
+pub struct Verbose<W: Write> {
+    out: W,
+    steps: usize
+}
+
+impl<W: Write> Verbose<W> {
+    pub fn new(out: W) -> Self {
+        Self { out, steps: 0 }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+impl<W: Write> Observer for Verbose<W> {
+    fn step(&mut self, state: &dyn Display) {
+        self.steps += 1;
+
+        // a trace is best effort, so it doesn't stop the solver if it can't
+        // be written
+        let state = state.to_string();
+        let _ = if state.contains('\n') {
+            write!(self.out, "step {}:\n{}", self.steps, state)
+        } else {
+            writeln!(self.out, "step {}: {}", self.steps, state)
+        };
+    }
+
+    fn note(&mut self, message: &dyn Display) {
+        let _ = writeln!(self.out, "    {}", message);
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct StepCounter {
+    pub steps: usize
+}
+
+impl Observer for StepCounter {
+    fn step(&mut self, _state: &dyn Display) {
+        self.steps += 1;
+    }
+}
+
+// keeps the state after every step
+#[derive(Debug, Default)]
+pub struct Frames {
+    pub frames: Vec<String>
+}
+
+impl Observer for Frames {
+    fn step(&mut self, state: &dyn Display) {
+        self.frames.push(state.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn count_down(from: u32, observer: &mut dyn Observer) {
+        for n in (0..from).rev() {
+            observer.note(&format_args!("{} left", n));
+            observer.step(&n);
+        }
+    }
+
+    #[test]
+    fn observers() {
+        let mut counter = StepCounter::default();
+        count_down(3, &mut counter);
+        assert_eq!(counter.steps, 3);
+
+        let mut frames = Frames::default();
+        count_down(3, &mut frames);
+        assert_eq!(frames.frames, vec!["2", "1", "0"]);
+
+        let mut verbose = Verbose::new(vec![]);
+        count_down(2, &mut verbose);
+        verbose.step(&"a\nb\n");
+        assert_eq!(
+            String::from_utf8(verbose.into_inner()).unwrap(),
+            "    1 left\nstep 1: 1\n    0 left\nstep 2: 0\nstep 3:\na\nb\n"
+        );
+
+        count_down(3, &mut ());
+    }
+}